@@ -51,11 +51,13 @@ lazy_static! {
             title: "Vine Whip".to_string(),
             fixed_damage: 40,
             effect: None,
+            ..Default::default()
         }],
         weakness: Some(EnergyType::Fire),
         retreat_cost: vec![EnergyType::Colorless],
         rarity: "◇".to_string(),
         booster_pack: "Genetic Apex (A1) Mewtwo".to_string(),
+        ..Default::default()
     });
 }
 
@@ -81,11 +83,13 @@ pub fn get_card_create(id: CardId) -> Card {
                 title: "Vine Whip".to_string(),
                 fixed_damage: 40,
                 effect: None,
+                ..Default::default()
             }],
             weakness: Some(EnergyType::Fire),
             retreat_cost: vec![EnergyType::Colorless],
             rarity: "◇".to_string(),
             booster_pack: "Genetic Apex (A1) Mewtwo".to_string(),
+            ..Default::default()
         }),
         _ => panic!("Card not found"),
     }
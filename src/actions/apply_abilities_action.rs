@@ -8,7 +8,7 @@ use crate::{
     actions::{
         apply_action_helpers::{handle_damage, Mutation, Mutations, Probabilities},
         mutations::{doutcome, doutcome_from_mutation},
-        shared_mutations::pokemon_search_outcomes,
+        shared_mutations::{forced_switch, pokemon_search_outcomes},
         Action, SimpleAction,
     },
     hooks::is_ultra_beast,
@@ -36,6 +36,7 @@ pub(crate) fn forecast_ability(
             panic!("Shadowy Spellbind is a passive ability")
         }
         AbilityId::A1177Weezing => doutcome(weezing_ability),
+        AbilityId::A1188PidgeotDriveOff => doutcome(pidgeot_drive_off),
         AbilityId::A1132Gardevoir => doutcome(gardevoir_ability),
         AbilityId::A1a006SerperiorJungleTotem => panic!("Serperior's ability is passive"),
         AbilityId::A2a010LeafeonExForestBreath => doutcome(leafon_ex_ability),
@@ -83,21 +84,26 @@ pub(crate) fn forecast_ability(
 
 fn butterfree_heal(_: &mut StdRng, state: &mut State, action: &Action) {
     debug!("Ability: Healing 20 damage from each Pokemon");
-    for pokemon in state.in_play_pokemon[action.actor].iter_mut().flatten() {
-        pokemon.heal(20);
-    }
+    state.heal_all(action.actor, 20);
 }
 
 fn weezing_ability(_: &mut StdRng, state: &mut State, action: &Action) {
     // Your opponent's Active Pokémon is now Poisoned.
     debug!("Weezing's ability: Poisoning opponent's active Pokemon");
-    let opponent = (action.actor + 1) % 2;
+    let opponent = State::opponent_of(action.actor);
     let opponent_active = state.in_play_pokemon[opponent][0]
         .as_mut()
         .expect("Opponent should have active pokemon");
     opponent_active.poisoned = true;
 }
 
+fn pidgeot_drive_off(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Switch out your opponent's Active Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)
+    debug!("Pidgeot's ability: Switching out opponent's active Pokemon");
+    let opponent = State::opponent_of(action.actor);
+    forced_switch(state, opponent, None);
+}
+
 fn gardevoir_ability(_: &mut StdRng, state: &mut State, action: &Action) {
     // Once during your turn, you may attach a Psychic Energy to your Active Pokémon.
     debug!("Gardevoir's ability: Attaching Psychic Energy to active Pokemon");
@@ -118,7 +124,7 @@ fn victreebel_ability(_: &mut StdRng, state: &mut State, action: &Action) {
     // Switch in 1 of your opponent's Benched Basic Pokémon to the Active Spot.
     debug!("Victreebel's ability: Switching opponent's benched basic Pokemon to active");
     let acting_player = action.actor;
-    let opponent_player = (acting_player + 1) % 2;
+    let opponent_player = State::opponent_of(acting_player);
     let possible_moves = state
         .enumerate_bench_pokemon(opponent_player)
         .filter(|(_, pokemon)| pokemon.card.is_basic())
@@ -181,7 +187,7 @@ fn greninja_shuriken(_: &mut StdRng, state: &mut State, action: &Action) {
         panic!("Greninja's ability should be triggered by UseAbility action");
     };
 
-    let opponent = (action.actor + 1) % 2;
+    let opponent = State::opponent_of(action.actor);
     let possible_moves = state
         .enumerate_in_play_pokemon(opponent)
         .map(|(in_play_idx, _)| SimpleAction::ApplyDamage {
@@ -247,8 +253,7 @@ fn espeon_ex_ability(_: &mut StdRng, state: &mut State, action: &Action) {
     // Once during your turn, if this Pokémon is in the Active Spot, you may heal 30 damage from 1 of your Pokémon.
     debug!("Espeon ex's Psychic Healing: Healing 30 damage from 1 of your Pokemon");
     let possible_moves = state
-        .enumerate_in_play_pokemon(action.actor)
-        .filter(|(_, pokemon)| pokemon.is_damaged())
+        .enumerate_damaged_pokemon(action.actor)
         .map(|(in_play_idx, _)| SimpleAction::Heal {
             in_play_idx,
             amount: 30,
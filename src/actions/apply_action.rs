@@ -8,7 +8,10 @@ use crate::{
         apply_abilities_action::forecast_ability,
         apply_action_helpers::{apply_common_mutation, Mutation},
     },
-    hooks::{get_retreat_cost, on_attach_energy, on_attach_tool, on_evolve, to_playable_card},
+    hooks::{
+        get_retreat_cost, get_tool_hp_bonus, on_attach_energy, on_attach_tool, on_evolve,
+        to_playable_card,
+    },
     models::{Card, EnergyType},
     state::State,
 };
@@ -46,9 +49,12 @@ pub fn forecast_action(state: &State, action: &Action) -> (Probabilities, Mutati
         | SimpleAction::AttachTool { .. }
         | SimpleAction::Evolve(_, _)
         | SimpleAction::Activate { .. }
+        | SimpleAction::ActivateWithEffect { .. }
         | SimpleAction::Retreat(_)
         | SimpleAction::ApplyDamage { .. }
         | SimpleAction::Heal { .. }
+        | SimpleAction::FullHeal { .. }
+        | SimpleAction::ActivateOpponent { .. }
         | SimpleAction::ApplyEeveeBagDamageBoost
         | SimpleAction::HealAllEeveeEvolutions
         | SimpleAction::Noop => forecast_deterministic_action(),
@@ -102,8 +108,8 @@ fn forecast_deterministic_action() -> (Probabilities, Mutations) {
 
 fn apply_deterministic_action(state: &mut State, action: &Action) {
     match &action.action {
-        SimpleAction::DrawCard { .. } => {
-            state.maybe_draw_card(action.actor);
+        SimpleAction::DrawCard { amount } => {
+            state.draw_cards(action.actor, *amount as usize);
         }
         SimpleAction::Attach {
             attachments,
@@ -131,7 +137,8 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
             state.in_play_pokemon[action.actor][*in_play_idx]
                 .as_mut()
                 .expect("Pokemon should be there if attaching tool to it")
-                .attached_tool = Some(*tool_id);
+                .attached_tools
+                .push(*tool_id);
             on_attach_tool(state, action.actor, *in_play_idx, *tool_id);
         }
         SimpleAction::MoveEnergy {
@@ -167,6 +174,16 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
         SimpleAction::Activate { in_play_idx } => {
             apply_retreat(action.actor, state, *in_play_idx, true);
         }
+        SimpleAction::ActivateWithEffect {
+            in_play_idx,
+            effect,
+            duration,
+        } => {
+            apply_retreat(action.actor, state, *in_play_idx, true);
+            state
+                .get_active_mut(action.actor)
+                .add_effect(*effect, *duration);
+        }
         SimpleAction::Retreat(position) => {
             apply_retreat(action.actor, state, *position, false);
         }
@@ -185,6 +202,15 @@ fn apply_deterministic_action(state: &mut State, action: &Action) {
         } => {
             apply_healing(action.actor, state, *in_play_idx, *amount, *cure_status);
         }
+        SimpleAction::ActivateOpponent { in_play_idx } => {
+            apply_opponent_switch(action.actor, state, *in_play_idx);
+        }
+        SimpleAction::FullHeal {
+            in_play_idx,
+            discard_energies,
+        } => {
+            apply_full_heal(action.actor, state, *in_play_idx, *discard_energies);
+        }
         SimpleAction::ApplyEeveeBagDamageBoost => {
             apply_eevee_bag_damage_boost(state);
         }
@@ -212,6 +238,22 @@ fn apply_healing(
     }
 }
 
+fn apply_full_heal(
+    acting_player: usize,
+    state: &mut State,
+    position: usize,
+    discard_energies: bool,
+) {
+    let pokemon = state.in_play_pokemon[acting_player][position]
+        .as_mut()
+        .expect("Pokemon should be there if healing it");
+    pokemon.full_heal();
+    if discard_energies {
+        let energies = std::mem::take(&mut pokemon.attached_energy);
+        state.discard_energies[acting_player].extend(energies);
+    }
+}
+
 /// is_free is analogous to "via retreat". If false, its because this comes from an Activate.
 /// Note: This might be called when a K.O. happens, so can't assume there is an active...
 fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_free: bool) {
@@ -265,7 +307,22 @@ fn apply_retreat(acting_player: usize, state: &mut State, bench_idx: usize, is_f
         pokemon.clear_status_and_effects();
     }
 
-    state.has_retreated = true;
+    // Promotions and forced switches don't use up the turn's retreat
+    if !is_free {
+        state.has_retreated = true;
+    }
+}
+
+/// Brings the opponent's bench_idx Pokemon to their Active Spot. Unlike a retreat, this
+/// doesn't count as the acting player's retreat for the turn.
+fn apply_opponent_switch(acting_player: usize, state: &mut State, bench_idx: usize) {
+    let opponent = State::opponent_of(acting_player);
+    state.in_play_pokemon[opponent].swap(0, bench_idx);
+
+    // The displaced Active goes to the Bench, which clears its status and effects
+    if let Some(pokemon) = state.in_play_pokemon[opponent][bench_idx].as_mut() {
+        pokemon.clear_status_and_effects();
+    }
 }
 
 // We will replace the PlayedCard, but taking into account the attached energy
@@ -287,10 +344,19 @@ pub(crate) fn apply_evolve(
             panic!("Basic pokemon do not evolve from others...");
         }
 
-        let damage_taken = from_pokemon.total_hp - from_pokemon.remaining_hp;
+        // Tools stay attached, and so does any HP they grant
+        let tools_hp_bonus: u32 = from_pokemon
+            .attached_tools
+            .iter()
+            .map(|tool_id| get_tool_hp_bonus(*tool_id))
+            .sum();
+        played_card.total_hp += tools_hp_bonus;
+        played_card.remaining_hp += tools_hp_bonus;
+
+        let damage_taken = state.damage_on(acting_player, position);
         played_card.remaining_hp -= damage_taken;
         played_card.attached_energy = from_pokemon.attached_energy.clone();
-        played_card.attached_tool = from_pokemon.attached_tool;
+        played_card.attached_tools = from_pokemon.attached_tools.clone();
         played_card.cards_behind = from_pokemon.cards_behind.clone();
         played_card.cards_behind.push(from_pokemon.card.clone());
         state.in_play_pokemon[acting_player][position] = Some(played_card);
@@ -377,7 +443,7 @@ fn forecast_shuffle_opponent_supporter(
     (
         vec![1.0],
         vec![Box::new(move |rng, state, _action| {
-            let opponent = (acting_player + 1) % 2;
+            let opponent = State::opponent_of(acting_player);
             state.transfer_card_from_hand_to_deck(opponent, &supporter_clone);
             state.decks[opponent].shuffle(false, rng);
             debug!(
@@ -396,7 +462,7 @@ fn forecast_discard_opponent_supporter(
     (
         vec![1.0],
         vec![Box::new(move |_rng, state, _action| {
-            let opponent = (acting_player + 1) % 2;
+            let opponent = State::opponent_of(acting_player);
             state.discard_card_from_hand(opponent, &supporter_clone);
             debug!(
                 "Mega Absol Ex: Discarded {:?} from opponent's hand",
@@ -505,8 +571,13 @@ mod tests {
     use rand::SeedableRng;
 
     use super::*;
+    use crate::actions::shared_mutations::forced_switch;
     use crate::card_ids::CardId;
     use crate::database::get_card_by_enum;
+    use crate::effects::CardEffect;
+    use crate::hooks::can_retreat;
+    use crate::test_helpers::load_test_decks;
+    use crate::tool_ids::ToolId;
     use crate::{
         models::{EnergyType, PlayedCard},
         Deck,
@@ -568,6 +639,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_evolve_keeps_tool_hp_bonus() {
+        let mut state = State::new(&Deck::default(), &Deck::default());
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][0]
+            .as_mut()
+            .unwrap()
+            .attached_tools
+            .push(ToolId::A2147GiantCape);
+        on_attach_tool(&mut state, 0, 0, ToolId::A2147GiantCape);
+        state.in_play_pokemon[0][0]
+            .as_mut()
+            .unwrap()
+            .apply_damage(30); // 60/90
+        state.hands[0] = vec![ivysaur.clone()];
+
+        apply_evolve(0, &mut state, &ivysaur, 0);
+
+        let evolved = state.in_play_pokemon[0][0].as_ref().unwrap();
+        assert_eq!(evolved.effective_total_hp(), 110); // 90 + 20
+        assert_eq!(evolved.remaining_hp, 80);
+        assert_eq!(evolved.attached_tools, vec![ToolId::A2147GiantCape]);
+    }
+
     #[test]
     fn test_forcefully_retreat() {
         let mut state = State::new(&Deck::default(), &Deck::default());
@@ -579,11 +676,7 @@ mod tests {
 
         // Forcefully Activate Primeape
         let mut rng: StdRng = StdRng::seed_from_u64(rand::random());
-        let action = Action {
-            actor: 0,
-            action: SimpleAction::Activate { in_play_idx: 2 },
-            is_stack: false,
-        };
+        let action = Action::new(0, SimpleAction::Activate { in_play_idx: 2 }, false);
         apply_action(&mut rng, &mut state, &action);
 
         assert_eq!(
@@ -596,6 +689,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_free_retreat_discards_no_energy() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let blastoise = get_card_by_enum(CardId::A1055Blastoise); // Retreat cost 3
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        state.turn_count = 3;
+        state.current_player = 0;
+        let mut active = to_playable_card(&blastoise, false);
+        active.attached_energy = vec![EnergyType::Water; 3];
+        active.add_effect(CardEffect::FreeRetreat, 0);
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&squirtle, false));
+
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let retreat = Action::new(0, SimpleAction::Retreat(1), false);
+        apply_action(&mut rng, &mut state, &retreat);
+
+        assert_eq!(state.get_active(0).card, squirtle);
+        let benched = state.in_play_pokemon[0][1].as_ref().unwrap();
+        assert_eq!(benched.attached_energy, vec![EnergyType::Water; 3]);
+        // Moving to the Bench clears the effect, so it only works once
+        assert!(benched.get_active_effects().is_empty());
+    }
+
+    #[test]
+    fn test_forced_switch_with_no_retreat_traps_new_active() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mankey = get_card_by_enum(CardId::A1141Mankey);
+        let primeape = get_card_by_enum(CardId::A1142Primeape);
+        state.turn_count = 3;
+        state.current_player = 0;
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&mankey, false));
+        state.in_play_pokemon[1][2] = Some(to_playable_card(&primeape, false));
+
+        // Drag out the opponent's Active and trap the new one during their next turn
+        forced_switch(&mut state, 1, Some((CardEffect::NoRetreat, 1)));
+        let (actor, choices) = state.move_generation_stack.last().unwrap().clone();
+        assert_eq!(actor, 1);
+        assert_eq!(choices.len(), 1);
+
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        let switch = Action::new(actor, choices[0].clone(), true);
+        apply_action(&mut rng, &mut state, &switch);
+        assert_eq!(state.get_active(1).card, primeape);
+        assert_eq!(state.in_play_pokemon[1][2].as_ref().unwrap().card, mankey);
+
+        let end_turn = Action::new(0, SimpleAction::EndTurn, false);
+        apply_action(&mut rng, &mut state, &end_turn);
+        assert_eq!(state.current_player, 1);
+        assert!(!can_retreat(&state));
+    }
+
     #[test]
     fn test_generate_energy_combinations_all_same_type() {
         // [Grass, Grass, Grass] -> 1 unique combo [Grass, Grass] with count 3
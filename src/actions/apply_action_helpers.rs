@@ -2,11 +2,12 @@ use log::debug;
 use rand::rngs::StdRng;
 
 use crate::{
-    actions::SimpleAction,
+    actions::{PendingChoice, SimpleAction},
     hooks::{
-        get_counterattack_damage, modify_damage, on_end_turn, on_knockout, should_poison_attacker,
+        compute_final_damage, get_counterattack_damage, on_end_turn, on_knockout,
+        should_poison_attacker, DamageContext,
     },
-    models::Card,
+    models::{Card, TrainerType},
     state::GameOutcome,
     State,
 };
@@ -29,16 +30,19 @@ pub(crate) fn forecast_end_turn(state: &State) -> (Probabilities, Mutations) {
         (
             vec![1.0],
             vec![Box::new({
-                |_, state, _| {
+                |rng, state, _| {
                     // advance current_player, but only advance "turn" (i.e. stay in 0) when both players done.
-                    state.current_player = (state.current_player + 1) % 2;
+                    state.current_player = state.opponent();
                     let both_players_initiated = state.in_play_pokemon[0][0].is_some()
                         && state.in_play_pokemon[1][0].is_some();
                     if both_players_initiated {
-                        // Actually start game (no energy generation)
+                        // Actually start game
                         state.turn_count = 1;
                         state.end_turn_maintenance();
                         state.queue_draw_action(state.current_player, 1);
+                        if state.generates_energy_this_turn(state.current_player) {
+                            state.generate_energy(rng);
+                        }
                     }
                 }
             })],
@@ -59,7 +63,8 @@ fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
             if pokemon.asleep {
                 sleeps_to_handle.push((player, i));
             }
-            if pokemon.paralyzed {
+            // Paralysis lasts through its owner's next turn, so it only clears after that turn
+            if pokemon.paralyzed && player == state.current_player {
                 paralyzed_to_handle.push((player, i));
             }
             if pokemon.poisoned {
@@ -86,11 +91,12 @@ fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
         let poisons_to_handle = poisons_to_handle.clone();
         let burns_to_handle = burns_to_handle.clone();
         outcomes.push(Box::new({
-            |_, state, action| {
+            |rng, state, action| {
                 // Important for these to happen before Pokemon Checkup (Zeraora, Suicune, etc)
                 on_end_turn(action.actor, state);
 
                 apply_pokemon_checkup(
+                    rng,
                     state,
                     sleeps_to_handle,
                     paralyzed_to_handle,
@@ -105,6 +111,7 @@ fn forecast_pokemon_checkup(state: &State) -> (Probabilities, Mutations) {
 }
 
 fn apply_pokemon_checkup(
+    rng: &mut StdRng,
     mutated_state: &mut State,
     sleeps_to_handle: Vec<(usize, usize)>,
     paralyzed_to_handle: Vec<(usize, usize)>,
@@ -136,15 +143,26 @@ fn apply_pokemon_checkup(
         debug!("{player}'s Pokemon {in_play_idx} is un-paralyzed");
     }
 
+    // Poison and burn damage happen at the same time, so their K.O.s are resolved together:
+    // all points are awarded before the owner is asked to promote a new Active, if needed.
+    let mut knockouts = vec![];
+    let mut add_knockouts = |new_knockouts: Vec<(usize, usize)>| {
+        for knockout in new_knockouts {
+            if !knockouts.contains(&knockout) {
+                knockouts.push(knockout);
+            }
+        }
+    };
+
     // Poison always deals 10 damage
     for (player, in_play_idx) in poisons_to_handle {
         let attacking_ref = (player, in_play_idx); // present it as self-damage
-        handle_damage(
+        add_knockouts(apply_damage(
             mutated_state,
             attacking_ref,
             &[(10, player, in_play_idx)],
             false,
-        );
+        ));
     }
 
     // Burn always deals 20 damage, then coin flip for healing
@@ -161,16 +179,17 @@ fn apply_pokemon_checkup(
 
         // Deal burn damage
         let attacking_ref = (*player, *in_play_idx); // present it as self-damage
-        handle_damage(
+        add_knockouts(apply_damage(
             mutated_state,
             attacking_ref,
             &[(20, *player, *in_play_idx)],
             false,
-        );
+        ));
     }
+    handle_knockouts(mutated_state, knockouts, false);
 
     // Advance turn
-    mutated_state.advance_turn();
+    mutated_state.advance_turn(rng);
 }
 
 fn generate_boolean_vectors(n: usize) -> Vec<Vec<bool>> {
@@ -194,6 +213,18 @@ pub(crate) fn handle_damage(
     targets: &[(u32, usize, usize)], // damage, target_player, in_play_idx
     is_from_active_attack: bool,
 ) {
+    let knockouts = apply_damage(state, attacking_ref, targets, is_from_active_attack);
+    handle_knockouts(state, knockouts, is_from_active_attack);
+}
+
+/// Same as `handle_damage`, but leaves K.O.s unresolved and returns the Pokémon left at 0 HP,
+/// so that several damage steps happening at once can resolve their K.O.s together.
+fn apply_damage(
+    state: &mut State,
+    attacking_ref: (usize, usize),
+    targets: &[(u32, usize, usize)],
+    is_from_active_attack: bool,
+) -> Vec<(usize, usize)> {
     let attacking_player = attacking_ref.0;
     let mut knockouts: Vec<(usize, usize)> = vec![];
 
@@ -201,8 +232,12 @@ pub(crate) fn handle_damage(
     let modified_targets = targets
         .iter()
         .map(|target_ref| {
-            let modified_damage =
-                modify_damage(state, attacking_ref, *target_ref, is_from_active_attack);
+            let modified_damage = compute_final_damage(&DamageContext::new(
+                state,
+                attacking_ref,
+                *target_ref,
+                is_from_active_attack,
+            ));
             (modified_damage, target_ref.1, target_ref.2)
         })
         .collect::<Vec<(u32, usize, usize)>>();
@@ -268,7 +303,16 @@ pub(crate) fn handle_damage(
             }
         }
     }
+    knockouts
+}
 
+/// Discards the knocked out Pokémon, awards points, checks for a winner and, if the
+/// game goes on, queues up promotion actions for any K.O.ed Active Pokémon.
+pub(crate) fn handle_knockouts(
+    state: &mut State,
+    knockouts: Vec<(usize, usize)>, // (ko_receiver, ko_pokemon_idx)
+    is_from_active_attack: bool,
+) {
     // Handle knockouts: Discard cards and award points (to potentially short-circuit promotions)
     for (ko_receiver, ko_pokemon_idx) in knockouts.clone() {
         // Call knockout hook (e.g., for Electrical Cord)
@@ -279,7 +323,7 @@ pub(crate) fn handle_damage(
             let ko_pokemon = state.in_play_pokemon[ko_receiver][ko_pokemon_idx]
                 .as_ref()
                 .expect("Pokemon should be there if knocked out");
-            let ko_initiator = (ko_receiver + 1) % 2;
+            let ko_initiator = State::opponent_of(ko_receiver);
             let points_won = ko_pokemon.card.get_knockout_points();
             state.points[ko_initiator] += points_won;
             debug!(
@@ -291,18 +335,35 @@ pub(crate) fn handle_damage(
         state.discard_from_play(ko_receiver, ko_pokemon_idx);
     }
 
-    // If game ends because of knockouts, set winner and return so as to short-circuit promotion logic
+    // A player wins by reaching the points to win (3 by default), or by K.O.ing the opponent's Active when they have
+    // no Bench left to promote from. Both conditions can be met by both players at once
+    // (e.g. counterattack K.O.s), in which case it's a tie.
     // Note even attacking player can lose by counterattack K.O.
-    if state.points[0] >= 3 && state.points[1] >= 3 {
-        debug!("Both players have 3 points, it's a tie");
-        state.winner = Some(GameOutcome::Tie);
-        return;
-    } else if state.points[0] >= 3 {
-        state.winner = Some(GameOutcome::Win(0));
-        return;
-    } else if state.points[1] >= 3 {
-        state.winner = Some(GameOutcome::Win(1));
-        return;
+    let has_enough_points = |player: usize| state.points[player] >= state.points_to_win;
+    let mut has_won = [has_enough_points(0), has_enough_points(1)];
+    for &(ko_receiver, ko_pokemon_idx) in &knockouts {
+        if ko_pokemon_idx == 0 && state.enumerate_bench_pokemon(ko_receiver).next().is_none() {
+            debug!("Player {ko_receiver} lost due to no bench pokemon");
+            has_won[State::opponent_of(ko_receiver)] = true;
+        }
+    }
+
+    // If game ends because of knockouts, set winner and return so as to short-circuit promotion logic
+    match has_won {
+        [true, true] => {
+            debug!("Both players met a winning condition, it's a tie");
+            state.winner = Some(GameOutcome::Tie);
+            return;
+        }
+        [true, false] => {
+            state.winner = Some(GameOutcome::Win(0));
+            return;
+        }
+        [false, true] => {
+            state.winner = Some(GameOutcome::Win(1));
+            return;
+        }
+        [false, false] => {}
     }
 
     // Queue up promotion actions if the game is still on after a knockout
@@ -311,37 +372,23 @@ pub(crate) fn handle_damage(
             continue; // Only promote if K.O. was on Active
         }
 
-        // If K.O. was Active and ko_receiver hasn't win, check if can select from Bench
-        let enumerated_bench_pokemon = state
-            .enumerate_bench_pokemon(ko_receiver)
-            .collect::<Vec<_>>();
-        if enumerated_bench_pokemon.is_empty() {
-            // If no bench pokemon, opponent loses
-            let ko_initiator = (ko_receiver + 1) % 2;
-            state.winner = Some(GameOutcome::Win(ko_initiator));
-            debug!("Player {ko_receiver} lost due to no bench pokemon");
+        let possible_moves = PendingChoice::PromoteActive.to_actions(state, ko_receiver);
+        debug!("Triggering Activate moves: {possible_moves:?} to player {ko_receiver}");
+        // insert right next to EndTurn, so that if this was triggered by an attack,
+        // we resolve any move_generation_stack effects from that attack first.
+        // If no EndTurn, just append to end (we could be coming through pokemon checkup poison).
+        let index_of_end_turn = state
+            .move_generation_stack
+            .iter()
+            .rposition(|(_, actions)| actions.contains(&SimpleAction::EndTurn));
+        if let Some(index_of_end_turn) = index_of_end_turn {
+            state
+                .move_generation_stack
+                .insert(index_of_end_turn + 1, (ko_receiver, possible_moves));
         } else {
-            let possible_moves = state
-                .enumerate_bench_pokemon(ko_receiver)
-                .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
-                .collect::<Vec<_>>();
-            debug!("Triggering Activate moves: {possible_moves:?} to player {ko_receiver}");
-            // insert right next to EndTurn, so that if this was triggered by an attack,
-            // we resolve any move_generation_stack effects from that attack first.
-            // If no EndTurn, just append to end (we could be coming through pokemon checkup poison).
-            let index_of_end_turn = state
+            state
                 .move_generation_stack
-                .iter()
-                .rposition(|(_, actions)| actions.contains(&SimpleAction::EndTurn));
-            if let Some(index_of_end_turn) = index_of_end_turn {
-                state
-                    .move_generation_stack
-                    .insert(index_of_end_turn + 1, (ko_receiver, possible_moves));
-            } else {
-                state
-                    .move_generation_stack
-                    .push((ko_receiver, possible_moves));
-            }
+                .push((ko_receiver, possible_moves));
         }
     }
 }
@@ -354,7 +401,12 @@ pub(crate) fn apply_common_mutation(state: &mut State, action: &Action) {
     }
     if let SimpleAction::Play { trainer_card } = &action.action {
         let card = Card::Trainer(trainer_card.clone());
-        state.discard_card_from_hand(action.actor, &card);
+        if trainer_card.trainer_card_type == TrainerType::Tool {
+            // Tools stay in play attached to a Pokémon, and are discarded along with it
+            state.remove_card_from_hand(action.actor, &card);
+        } else {
+            state.discard_card_from_hand(action.actor, &card);
+        }
         if card.is_support() {
             state.has_played_support = true;
         }
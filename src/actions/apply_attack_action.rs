@@ -3,27 +3,29 @@ use rand::{rngs::StdRng, Rng};
 
 use crate::{
     actions::{
-        apply_action_helpers::handle_damage,
+        apply_action_helpers::{handle_damage, handle_knockouts},
         apply_evolve,
         mutations::{doutcome, doutcome_from_mutation},
-        Action,
+        Action, PendingChoice,
     },
     attack_ids::AttackId,
     effects::{CardEffect, TurnEffect},
     hooks::{can_evolve_into, get_stage},
-    models::{Card, EnergyType, StatusCondition},
+    models::{AttackCoinFlip, Card, EnergyType, StatusCondition},
     State,
 };
 
 use super::{
-    apply_action_helpers::{Mutations, Probabilities},
+    apply_action_helpers::{Mutation, Mutations, Probabilities},
+    coin_flips::flip_until_tails_outcomes,
     mutations::{
         active_damage_doutcome, active_damage_effect_doutcome, active_damage_effect_mutation,
         active_damage_mutation, build_status_effect, damage_effect_doutcome,
         index_active_damage_doutcome,
     },
     shared_mutations::{
-        pokemon_search_outcomes, pokemon_search_outcomes_by_type, search_and_bench_by_name,
+        forced_switch, pokemon_search_outcomes, pokemon_search_outcomes_by_type,
+        search_and_bench_by_name, shuffle_active_into_deck,
     },
     SimpleAction,
 };
@@ -37,6 +39,16 @@ pub(crate) fn forecast_attack(
     let active = state.get_active(acting_player);
     let attack = active.card.get_attacks()[index].clone();
     trace!("Forecasting attack: {active:?} {attack:?}");
+    if let Some(coin_flip) = attack.coin_flip {
+        return match coin_flip {
+            AttackCoinFlip::SelfKnockOutOnTails => {
+                damage_or_self_knockout_attack(attack.fixed_damage)
+            }
+            AttackCoinFlip::BenchHitOnHeads { bench_damage } => {
+                coin_flip_bench_hit_attack(attack.fixed_damage, bench_damage)
+            }
+        };
+    }
     if attack.effect.is_none() {
         active_damage_doutcome(attack.fixed_damage)
     } else {
@@ -238,12 +250,10 @@ fn forecast_effect_attack(
         }
         AttackId::A1154HitmonleeStretchKick => direct_damage(30, true),
         AttackId::A1163GrapploctKnockBack => knock_back_attack(60),
-        AttackId::A1165ArbokCorner => damage_and_card_effect_attack(
-            index,
-            (state.current_player + 1) % 2,
-            1,
-            CardEffect::NoRetreat,
-        ),
+        AttackId::A1151CuboneGrowl => attack_damage_debuff_attack(index, acting_player, 20),
+        AttackId::A1165ArbokCorner => {
+            damage_and_card_effect_attack(index, state.opponent(), 1, CardEffect::NoRetreat)
+        }
         AttackId::A1171NidokingPoisonHorn => damage_status_attack(90, StatusCondition::Poisoned),
         AttackId::A1174GrimerPoisonGas => damage_status_attack(10, StatusCondition::Poisoned),
         AttackId::A1178MawileCrunch => mawile_crunch(),
@@ -254,6 +264,7 @@ fn forecast_effect_attack(
         AttackId::A1203KangaskhanDizzyPunch => {
             probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 30, 60])
         }
+        AttackId::A1210AerodactylPrimalWingbeat => primal_wingbeat(),
         AttackId::A1a003CelebiExPowerfulBloom => celebi_powerful_bloom(acting_player, state),
         AttackId::A1a010PonytaStomp => probabilistic_damage_attack(vec![0.5, 0.5], vec![10, 40]),
         AttackId::A1a011RapidashRisingLunge => {
@@ -263,7 +274,7 @@ fn forecast_effect_attack(
         AttackId::A1a017MagikarpLeapOut | AttackId::A4a021FeebasLeapOut => teleport_attack(),
         AttackId::A1a021LumineonAquaLiner => direct_damage(50, true),
         AttackId::A1a026RaichuGigashock => {
-            let opponent = (state.current_player + 1) % 2;
+            let opponent = state.opponent();
             let targets: Vec<(u32, usize)> = state
                 .enumerate_bench_pokemon(opponent)
                 .map(|(idx, _)| (20, idx))
@@ -329,6 +340,7 @@ fn forecast_effect_attack(
         AttackId::A2a001HeracrossSingleHornThrow => {
             probabilistic_damage_attack(vec![0.25, 0.75], vec![120, 50])
         }
+        AttackId::A2a030ClefableMoonblast => attack_damage_debuff_attack(index, acting_player, 30),
         AttackId::A2a063SnorlaxCollapse => {
             damage_and_self_status_attack(100, StatusCondition::Asleep)
         }
@@ -342,6 +354,9 @@ fn forecast_effect_attack(
             bench_count_attack(acting_player, state, 70, 20, None)
         }
         AttackId::A2035PiplupNap => self_heal_attack(20, index),
+        AttackId::A2046GlaceonIceBeam => {
+            damage_chance_status_attack(60, 0.5, StatusCondition::Paralyzed)
+        }
         AttackId::A2b010CharizardExStoke => self_charge_active_attack(0, EnergyType::Fire, 3),
         AttackId::A2b032MrMimeJuggling => probabilistic_damage_attack(
             vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
@@ -378,12 +393,9 @@ fn forecast_effect_attack(
         AttackId::A3061AlolanGolemSuperZapCannon => {
             self_energy_discard_attack(0, vec![EnergyType::Lightning, EnergyType::Lightning])
         }
-        AttackId::A3070SableyeCorner => damage_and_card_effect_attack(
-            index,
-            (state.current_player + 1) % 2,
-            1,
-            CardEffect::NoRetreat,
-        ),
+        AttackId::A3070SableyeCorner => {
+            damage_and_card_effect_attack(index, state.opponent(), 1, CardEffect::NoRetreat)
+        }
         AttackId::A3071SpoinkPsycharge => self_charge_active_attack(0, EnergyType::Psychic, 1),
         AttackId::A3114GarbodorSuperPoisonBreath => {
             damage_status_attack(70, StatusCondition::Poisoned)
@@ -406,6 +418,9 @@ fn forecast_effect_attack(
         AttackId::A3a033LycanrocExLycanfang => {
             self_energy_discard_attack(0, vec![EnergyType::Fighting])
         }
+        AttackId::A3a038AlolanPersianFakeOut => {
+            damage_chance_status_attack(50, 0.5, StatusCondition::Paralyzed)
+        }
         AttackId::A3a042NihilegoNewWave => damage_status_attack(30, StatusCondition::Poisoned),
         AttackId::A3a043GuzzlordExGrindcore => guzzlord_ex_grindcore_attack(),
         AttackId::A3a044Poipole2Step => {
@@ -457,6 +472,12 @@ fn forecast_effect_attack(
         AttackId::A3b020VanilluxeDoubleSpin => {
             probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![0, 80, 160])
         }
+        AttackId::A3b026JoltikJolt => {
+            damage_chance_status_attack(10, 0.5, StatusCondition::Paralyzed)
+        }
+        AttackId::A3b047MawilePowerfulVise => {
+            damage_chance_status_attack(30, 0.5, StatusCondition::Paralyzed)
+        }
         AttackId::A3b053DragoniteExGigaImpact => giga_impact_attack(),
         AttackId::A3b055EeveeCollect => draw_and_damage_outcome(0),
         AttackId::A3b057SnorlaxExFlopDownPunch => {
@@ -474,6 +495,14 @@ fn forecast_effect_attack(
             attach_energy_to_benched_basic(acting_player, EnergyType::Fire)
         }
         AttackId::A4053QwilfishPoisonSting => damage_status_attack(20, StatusCondition::Poisoned),
+        AttackId::A4059SuicuneCureStream => attack_damage_debuff_attack(index, acting_player, 20),
+        AttackId::A4075SmoochumShiveryWave => {
+            damage_based_on_opponent_energy(acting_player, state, 0, 20)
+        }
+        AttackId::A4076JynxAttractSmack => {
+            damage_chance_status_attack(30, 0.5, StatusCondition::Paralyzed)
+        }
+        AttackId::A4078TogepiCharm => attack_damage_debuff_attack(index, acting_player, 20),
         AttackId::A4066PichuCracklyToss => {
             attach_energy_to_benched_basic(acting_player, EnergyType::Lightning)
         }
@@ -498,6 +527,9 @@ fn forecast_effect_attack(
             CardEffect::ReducedDamage { amount: 20 },
         ),
         AttackId::A4134EeveeFindAFriend => pokemon_search_outcomes(acting_player, state, false),
+        AttackId::A4144DunsparceSuddenFlash => {
+            damage_chance_status_attack(20, 0.5, StatusCondition::Paralyzed)
+        }
         AttackId::A4146UrsaringSwingAround => {
             probabilistic_damage_attack(vec![0.25, 0.5, 0.25], vec![60, 80, 100])
         }
@@ -550,6 +582,9 @@ fn forecast_effect_attack(
         AttackId::B1161MareaniePoisonSting => damage_status_attack(0, StatusCondition::Poisoned),
         AttackId::B1157HydreigonHyperRay => thunderbolt_attack(130),
         AttackId::B1179GoodraSpiralDrain => self_heal_attack(40, 0),
+        AttackId::B1187MiltankBodySlam => {
+            damage_chance_status_attack(40, 0.5, StatusCondition::Paralyzed)
+        }
         AttackId::B1196SwabluSing => damage_status_attack(0, StatusCondition::Asleep),
         AttackId::PA056EkansPoisonSting => damage_status_attack(0, StatusCondition::Poisoned),
     }
@@ -562,7 +597,7 @@ fn mega_burning_attack() -> (Probabilities, Mutations) {
         state.discard_from_active(action.actor, &[EnergyType::Fire]);
 
         // Apply burned status
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let opponent_active = state.get_active_mut(opponent);
         opponent_active.apply_status_condition(StatusCondition::Burned);
     })
@@ -648,7 +683,7 @@ fn palkia_dimensional_storm(state: &State) -> (Probabilities, Mutations) {
     // This attack does 150 damage to Active, and 20 to every bench pokemon
     // it then also discards 3 energies. This is deterministic
     let targets: Vec<(u32, usize)> = state
-        .enumerate_in_play_pokemon((state.current_player + 1) % 2)
+        .enumerate_in_play_pokemon(state.opponent())
         .map(|(idx, _)| (20, idx))
         .chain(std::iter::once((150, 0))) // Add active Pokémon directly
         .collect();
@@ -834,7 +869,7 @@ fn all_bench_count_attack(
     state: &State,
     damage_per: u32,
 ) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let your_bench_count = state.enumerate_bench_pokemon(acting_player).count() as u32;
     let opponent_bench_count = state.enumerate_bench_pokemon(opponent).count() as u32;
     let total_bench_count = your_bench_count + opponent_bench_count;
@@ -845,7 +880,7 @@ fn all_bench_count_attack(
 fn self_benched_damage(active_damage: u32, self_bench_damage: u32) -> (Probabilities, Mutations) {
     doutcome_from_mutation(Box::new(
         move |_: &mut StdRng, state: &mut State, action: &Action| {
-            let opponent = (action.actor + 1) % 2;
+            let opponent = State::opponent_of(action.actor);
             let choices: Vec<_> = state
                 .enumerate_bench_pokemon(action.actor)
                 .map(|(in_play_idx, _)| SimpleAction::ApplyDamage {
@@ -866,25 +901,53 @@ fn active_and_choice_bench_attack(
     active_damage: u32,
     bench_damage: u32,
 ) -> (Probabilities, Mutations) {
-    doutcome_from_mutation(Box::new(
-        move |_: &mut StdRng, state: &mut State, action: &Action| {
-            let opponent = (action.actor + 1) % 2;
-            let choices: Vec<_> = state
-                .enumerate_bench_pokemon(opponent)
-                .map(|(in_play_idx, _)| SimpleAction::ApplyDamage {
-                    attacking_ref: (action.actor, 0),
-                    targets: vec![
-                        (active_damage, opponent, 0),
-                        (bench_damage, opponent, in_play_idx),
-                    ],
-                    is_from_active_attack: true,
-                })
-                .collect();
-            state.move_generation_stack.push((action.actor, choices));
-        },
+    doutcome_from_mutation(active_and_choice_bench_mutation(
+        active_damage,
+        bench_damage,
     ))
 }
 
+/// For attacks that flip a coin and, only if heads, also hit 1 of the opponent's Benched
+/// Pokémon (of the attacker's choice). The Active Pokémon is damaged either way.
+fn coin_flip_bench_hit_attack(active_damage: u32, bench_damage: u32) -> (Probabilities, Mutations) {
+    (
+        vec![0.5, 0.5],
+        vec![
+            active_and_choice_bench_mutation(active_damage, bench_damage),
+            active_damage_mutation(active_damage),
+        ],
+    )
+}
+
+/// Queues the choice of which Benched Pokémon also takes `bench_damage`. Damage (and any
+/// resulting K.O.s) is resolved together once chosen. Without a Bench, only the Active is hit.
+fn active_and_choice_bench_mutation(active_damage: u32, bench_damage: u32) -> Mutation {
+    Box::new(move |_: &mut StdRng, state: &mut State, action: &Action| {
+        let opponent = State::opponent_of(action.actor);
+        let choices: Vec<_> = state
+            .enumerate_bench_pokemon(opponent)
+            .map(|(in_play_idx, _)| SimpleAction::ApplyDamage {
+                attacking_ref: (action.actor, 0),
+                targets: vec![
+                    (active_damage, opponent, 0),
+                    (bench_damage, opponent, in_play_idx),
+                ],
+                is_from_active_attack: true,
+            })
+            .collect();
+        if choices.is_empty() {
+            handle_damage(
+                state,
+                (action.actor, 0),
+                &[(active_damage, opponent, 0)],
+                true,
+            );
+            return;
+        }
+        state.move_generation_stack.push((action.actor, choices));
+    })
+}
+
 fn self_charge_active_attack(
     damage: u32,
     energy_type: EnergyType,
@@ -900,7 +963,7 @@ fn self_charge_active_attack(
 /// It will queue (via move_generation_stack) for the user to choose a pokemon to damage.
 fn direct_damage(damage: u32, bench_only: bool) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(0, move |_, state, action| {
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let mut choices = Vec::new();
         if bench_only {
             for (in_play_idx, _) in state.enumerate_bench_pokemon(opponent) {
@@ -930,7 +993,7 @@ fn direct_damage(damage: u32, bench_only: bool) -> (Probabilities, Mutations) {
 /// e.g. Decidueye ex's Pierce the Pain
 fn direct_damage_if_damaged(damage: u32) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(0, move |_, state, action| {
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let mut choices = Vec::new();
         for (in_play_idx, pokemon) in state.enumerate_in_play_pokemon(opponent) {
             // Only add as a target if the Pokémon has damage (remaining_hp < total_hp)
@@ -963,7 +1026,7 @@ fn luxray_volt_bolt() -> (Probabilities, Mutations) {
         state.discard_from_active(action.actor, &to_discard);
 
         // Create choices for which opponent's Pokémon to damage
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let mut choices = Vec::new();
         for (in_play_idx, _) in state.enumerate_in_play_pokemon(opponent) {
             choices.push(SimpleAction::ApplyDamage {
@@ -991,7 +1054,7 @@ fn self_energy_discard_attack(
 /// For attacks that deal damage and discard random energy from opponent's active Pokémon
 fn damage_and_discard_energy(damage: u32, discard_count: usize) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |rng, state, action| {
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let active = state.get_active_mut(opponent);
 
         for _ in 0..discard_count {
@@ -1013,7 +1076,7 @@ fn damage_and_discard_opponent_deck(
     discard_count: usize,
 ) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |_, state, action| {
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
 
         for _ in 0..discard_count {
             if let Some(card) = state.decks[opponent].draw() {
@@ -1027,28 +1090,22 @@ fn damage_and_discard_opponent_deck(
 
 fn guzzlord_ex_grindcore_attack() -> (Probabilities, Mutations) {
     // Flip coins until tails - capped at 5 heads for practicality
-    let probabilities = vec![0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625];
-    let mut outcomes: Mutations = vec![];
-    for energies_to_remove in 0..6 {
-        outcomes.push(active_damage_effect_mutation(
-            30,
-            move |_, state, action| {
-                let opponent = (action.actor + 1) % 2;
-                let active = state.get_active_mut(opponent);
-
-                for _ in 0..energies_to_remove {
-                    if active.attached_energy.is_empty() {
-                        break; // No more energy to discard
-                    }
-                    // NOTE: Using pop() instead of random selection to avoid expanding the game tree.
-                    // This is a simplification - the card text says "random Energy" but we always
-                    // remove the last one for performance reasons.
-                    active.attached_energy.pop();
+    flip_until_tails_outcomes(5, |energies_to_remove| {
+        active_damage_effect_mutation(30, move |_, state, action| {
+            let opponent = State::opponent_of(action.actor);
+            let active = state.get_active_mut(opponent);
+
+            for _ in 0..energies_to_remove {
+                if active.attached_energy.is_empty() {
+                    break; // No more energy to discard
                 }
-            },
-        ));
-    }
-    (probabilities, outcomes)
+                // NOTE: Using pop() instead of random selection to avoid expanding the game tree.
+                // This is a simplification - the card text says "random Energy" but we always
+                // remove the last one for performance reasons.
+                active.attached_energy.pop();
+            }
+        })
+    })
 }
 
 /// For attacks that deal damage to opponent and also damage themselves
@@ -1059,6 +1116,20 @@ fn self_damage_attack(damage: u32, self_damage: u32) -> (Probabilities, Mutation
     })
 }
 
+/// For high-risk attacks that deal damage and then flip a coin. If tails, the attacker is
+/// Knocked Out (and the opponent gets points for it as usual).
+fn damage_or_self_knockout_attack(damage: u32) -> (Probabilities, Mutations) {
+    let tails: Mutation = Box::new(move |_, state, action| {
+        let opponent = State::opponent_of(action.actor);
+        handle_damage(state, (action.actor, 0), &[(damage, opponent, 0)], true);
+        // Attacker could already be gone (e.g. Rocky Helmet), or the game could be over.
+        if state.winner.is_none() && state.in_play_pokemon[action.actor][0].is_some() {
+            handle_knockouts(state, vec![(action.actor, 0)], false);
+        }
+    });
+    (vec![0.5, 0.5], vec![active_damage_mutation(damage), tails])
+}
+
 /// For attacks that deal damage and apply a status effect (e.g. Wigglituff Ex)
 fn damage_status_attack(damage: u32, status: StatusCondition) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, build_status_effect(status))
@@ -1126,27 +1197,9 @@ fn probabilistic_damage_attack(
 }
 
 /// For attacks that flip a coin until tails, dealing damage for each heads.
-/// Uses geometric distribution truncated at a reasonable number to avoid infinite outcomes.
+/// Truncated at 8 heads to keep the probability space manageable.
 fn flip_until_tails_attack(damage_per_heads: u32) -> (Probabilities, Mutations) {
-    // Truncate at 8 heads to keep the probability space manageable
-    // P(k heads) = (1/2)^(k+1) for k = 0, 1, 2, ...
-    let max_heads = 8;
-    let mut probabilities = Vec::new();
-    let mut damages = Vec::new();
-
-    for heads in 0..=max_heads {
-        let probability = 0.5_f64.powi(heads as i32 + 1);
-        probabilities.push(probability);
-        damages.push(heads * damage_per_heads);
-    }
-
-    // Ensure probabilities sum to 1 by adjusting the last one for any floating point errors
-    let sum: f64 = probabilities.iter().sum();
-    if let Some(last) = probabilities.last_mut() {
-        *last += 1.0 - sum;
-    }
-
-    probabilistic_damage_attack(probabilities, damages)
+    flip_until_tails_outcomes(8, |heads| active_damage_mutation(heads * damage_per_heads))
 }
 
 fn self_heal_attack(heal: u32, index: usize) -> (Probabilities, Mutations) {
@@ -1179,6 +1232,21 @@ fn damage_and_card_effect_attack(
     })
 }
 
+/// For attacks like Cubone's Growl: "During your opponent's next turn, attacks used by the
+/// Defending Pokémon do -X damage."
+fn attack_damage_debuff_attack(
+    index: usize,
+    acting_player: usize,
+    amount: u32,
+) -> (Probabilities, Mutations) {
+    damage_and_card_effect_attack(
+        index,
+        State::opponent_of(acting_player),
+        1,
+        CardEffect::AttackDamageDebuff { amount },
+    )
+}
+
 fn cannot_use_attack_next_turn(
     index: usize,
     acting_player: usize,
@@ -1222,7 +1290,7 @@ fn discard_all_energy_of_type_attack(
 
 fn articuno_ex_blizzard(state: &State) -> (Probabilities, Mutations) {
     // Blizzard: 80 to active, 10 to each opponent's benched Pokémon
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let mut targets: Vec<(u32, usize)> = state
         .enumerate_bench_pokemon(opponent)
         .map(|(idx, _)| (10, idx))
@@ -1234,7 +1302,7 @@ fn articuno_ex_blizzard(state: &State) -> (Probabilities, Mutations) {
 
 fn alolan_ninetales_blizzard(state: &State) -> (Probabilities, Mutations) {
     // Blizzard: 60 to active, 20 to each opponent's benched Pokémon
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let mut targets: Vec<(u32, usize)> = state
         .enumerate_bench_pokemon(opponent)
         .map(|(idx, _)| (20, idx))
@@ -1246,7 +1314,7 @@ fn alolan_ninetales_blizzard(state: &State) -> (Probabilities, Mutations) {
 
 fn alakazam_psychic_suppression(state: &State) -> (Probabilities, Mutations) {
     // Psychic Suppression: 80 to active, 20 to each opponent's benched Pokémon that has energy
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let mut targets: Vec<(u32, usize)> = state
         .enumerate_bench_pokemon(opponent)
         .filter(|(_, pokemon)| !pokemon.attached_energy.is_empty())
@@ -1280,7 +1348,7 @@ fn extra_damage_if_hurt(
     acting_player: usize,
     state: &State,
 ) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let opponent_active = state.get_active(opponent);
     if opponent_active.remaining_hp < opponent_active.total_hp {
         active_damage_doutcome(base + extra)
@@ -1308,7 +1376,7 @@ fn damage_based_on_opponent_energy(
     base_damage: u32,
     damage_per_energy: u32,
 ) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let opponent_active = state.get_active(opponent);
     let damage = base_damage + (opponent_active.attached_energy.len() as u32) * damage_per_energy;
     active_damage_doutcome(damage)
@@ -1320,7 +1388,7 @@ fn extra_damage_if_opponent_is_ex(
     base_damage: u32,
     extra_damage: u32,
 ) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let opponent_active = state.get_active(opponent);
     let damage = if opponent_active.card.is_ex() {
         base_damage + extra_damage
@@ -1345,17 +1413,24 @@ fn extra_damage_if_tool_attached(
     active_damage_doutcome(damage)
 }
 
+/// For Aerodactyl's Primal Wingbeat: Flip a coin. If heads, your opponent shuffles their
+/// Active Pokémon into their deck.
+fn primal_wingbeat() -> (Probabilities, Mutations) {
+    let probabilities = vec![0.5, 0.5];
+    let mutations: Mutations = vec![
+        Box::new(|rng, state, action| {
+            let opponent = State::opponent_of(action.actor);
+            shuffle_active_into_deck(rng, state, opponent);
+        }),
+        active_damage_mutation(0),
+    ];
+    (probabilities, mutations)
+}
+
 fn knock_back_attack(damage: u32) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(damage, move |_, state, action| {
-        let opponent = (action.actor + 1) % 2;
-        let mut choices = Vec::new();
-        for (in_play_idx, _) in state.enumerate_bench_pokemon(opponent) {
-            choices.push(SimpleAction::Activate { in_play_idx });
-        }
-        if choices.is_empty() {
-            return; // No benched pokemon to knock back
-        }
-        state.move_generation_stack.push((opponent, choices));
+        let opponent = State::opponent_of(action.actor);
+        forced_switch(state, opponent, None);
     })
 }
 
@@ -1366,7 +1441,7 @@ fn mawile_crunch() -> (Probabilities, Mutations) {
         active_damage_mutation(20), // Tails: just damage
         active_damage_effect_mutation(20, move |rng, state, action| {
             // Heads: damage + discard random energy
-            let opponent = (action.actor + 1) % 2;
+            let opponent = State::opponent_of(action.actor);
             let active = state.get_active_mut(opponent);
 
             if !active.attached_energy.is_empty() {
@@ -1412,7 +1487,7 @@ fn brave_buddies_attack(state: &State) -> (Probabilities, Mutations) {
 
 /// For Absol's Unseen Claw (A3 112): Deals 20 damage, +60 if opponent's Active has a Special Condition
 fn unseen_claw_attack(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let opponent_active = state.get_active(opponent);
     let damage = if opponent_active.has_status_condition() {
         80 // 20 + 60
@@ -1429,7 +1504,7 @@ fn ominous_claw_attack(acting_player: usize, _state: &State) -> (Probabilities,
     let mutations: Mutations = vec![
         // Heads: damage + discard supporter
         active_damage_effect_mutation(50, move |_, state, _action| {
-            let opponent = (acting_player + 1) % 2;
+            let opponent = State::opponent_of(acting_player);
             let possible_discards: Vec<SimpleAction> = state
                 .iter_hand_supporters(opponent)
                 .map(|card| SimpleAction::DiscardOpponentSupporter {
@@ -1452,7 +1527,7 @@ fn ominous_claw_attack(acting_player: usize, _state: &State) -> (Probabilities,
 /// For Mega Absol ex's Darkness Claw: Deals 80 damage and lets player discard a Supporter from opponent's hand
 fn darkness_claw_attack(acting_player: usize, _state: &State) -> (Probabilities, Mutations) {
     active_damage_effect_doutcome(80, move |_, state, _action| {
-        let opponent = (acting_player + 1) % 2;
+        let opponent = State::opponent_of(acting_player);
         let possible_discards: Vec<SimpleAction> = state
             .iter_hand_supporters(opponent)
             .map(|card| SimpleAction::DiscardOpponentSupporter {
@@ -1477,16 +1552,8 @@ fn dirty_throw_attack(acting_player: usize, state: &State) -> (Probabilities, Mu
     } else {
         // Player has cards in hand, deal 70 damage and queue discard decision
         active_damage_effect_doutcome(70, move |_, state, action| {
-            let possible_discards: Vec<SimpleAction> = state.hands[action.actor]
-                .iter()
-                .map(|card| SimpleAction::DiscardOwnCard { card: card.clone() })
-                .collect();
-
-            if !possible_discards.is_empty() {
-                state
-                    .move_generation_stack
-                    .push((action.actor, possible_discards));
-            }
+            let hand = state.hands[action.actor].clone();
+            state.push_pending_choice(action.actor, PendingChoice::Discard(hand));
         })
     }
 }
@@ -1495,7 +1562,7 @@ fn mega_ampharos_lightning_lancer() -> (Probabilities, Mutations) {
     // 1 of your opponent's Benched Pokémon is chosen at random 3 times.
     // For each time a Pokémon was chosen, also do 20 damage to it.
     doutcome(|rng, state, action| {
-        let opponent = (action.actor + 1) % 2;
+        let opponent = State::opponent_of(action.actor);
         let targets: Vec<(u32, usize, usize)> = generate_random_spread_indices(rng, state, true, 3)
             .into_iter()
             .map(|idx| (20, opponent, idx))
@@ -1513,7 +1580,7 @@ fn generate_random_spread_indices(
     bench_only: bool,
     count: usize,
 ) -> Vec<usize> {
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let mut targets = vec![];
     for _ in 0..count {
         let possible_indices: Vec<usize> = if bench_only {
@@ -1543,6 +1610,7 @@ mod test {
 
     use crate::{
         actions::Action, card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card,
+        state::GameOutcome,
     };
 
     use super::*;
@@ -1551,11 +1619,7 @@ mod test {
     fn test_arceus_does_90_damage() {
         let mut rng = StdRng::seed_from_u64(0);
         let mut state = State::default();
-        let action = Action {
-            actor: 0,
-            action: SimpleAction::Attack(0),
-            is_stack: false,
-        };
+        let action = Action::new(0, SimpleAction::Attack(0), false);
 
         let receiver = get_card_by_enum(CardId::A1003Venusaur); // 160 hp
         state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
@@ -1570,6 +1634,105 @@ mod test {
         assert_eq!(state.get_active(1).remaining_hp, 70);
     }
 
+    /// Bulbasaur (Vine Whip, 40 damage) flagged to flip for a self-K.O., with a Bench to
+    /// promote from, against a 160 HP Venusaur.
+    fn self_knockout_attack_state() -> State {
+        let mut attacker = get_card_by_enum(CardId::A1001Bulbasaur);
+        if let Card::Pokemon(pokemon_card) = &mut attacker {
+            pokemon_card.attacks[0].coin_flip = Some(AttackCoinFlip::SelfKnockOutOnTails);
+        }
+        let mut state = State::default();
+        let receiver = get_card_by_enum(CardId::A1003Venusaur);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&receiver, false));
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker, false));
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&attacker, false));
+        state
+    }
+
+    #[test]
+    fn test_self_knockout_attack_on_tails() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = self_knockout_attack_state();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+
+        let (probabilities, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        lazy_mutations.remove(1)(&mut rng, &mut state, &action); // tails
+
+        assert_eq!(state.get_active(1).remaining_hp, 120);
+        assert!(state.in_play_pokemon[0][0].is_none());
+        assert_eq!(state.points, [0, 1]);
+        assert_eq!(
+            state.move_generation_stack.last(),
+            Some(&(0, vec![SimpleAction::Activate { in_play_idx: 1 }]))
+        );
+    }
+
+    #[test]
+    fn test_self_knockout_attack_on_heads() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = self_knockout_attack_state();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action); // heads
+
+        assert_eq!(state.get_active(1).remaining_hp, 120);
+        assert_eq!(state.get_active(0).remaining_hp, 70);
+        assert_eq!(state.points, [0, 0]);
+        assert!(state.move_generation_stack.is_empty());
+    }
+
+    /// Bulbasaur (Vine Whip, 40 damage) flagged to flip for a 20 damage Bench hit, against
+    /// 160 HP Venusaurs in the opponent's Active Spot and Bench.
+    fn coin_flip_bench_hit_state() -> State {
+        let mut attacker = get_card_by_enum(CardId::A1001Bulbasaur);
+        if let Card::Pokemon(pokemon_card) = &mut attacker {
+            pokemon_card.attacks[0].coin_flip =
+                Some(AttackCoinFlip::BenchHitOnHeads { bench_damage: 20 });
+        }
+        let mut state = State::default();
+        let venusaur = get_card_by_enum(CardId::A1003Venusaur);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&venusaur, false));
+        state.in_play_pokemon[1][2] = Some(to_playable_card(&venusaur, false));
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker, false));
+        state
+    }
+
+    #[test]
+    fn test_coin_flip_bench_hit_on_heads() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = coin_flip_bench_hit_state();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+
+        let (probabilities, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        assert_eq!(probabilities, vec![0.5, 0.5]);
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action); // heads
+
+        let (actor, choices) = state.move_generation_stack.pop().unwrap();
+        assert_eq!(actor, 0);
+        assert_eq!(choices.len(), 1);
+        let choice = Action::new(0, choices[0].clone(), true);
+        crate::actions::apply_action(&mut rng, &mut state, &choice);
+
+        assert_eq!(state.get_remaining_hp(1, 0), 120);
+        assert_eq!(state.get_remaining_hp(1, 2), 140);
+    }
+
+    #[test]
+    fn test_coin_flip_bench_hit_on_tails() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = coin_flip_bench_hit_state();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+
+        let (_, mut lazy_mutations) = forecast_attack(0, &state, 0);
+        lazy_mutations.remove(1)(&mut rng, &mut state, &action); // tails
+
+        assert_eq!(state.get_remaining_hp(1, 0), 120);
+        assert_eq!(state.get_remaining_hp(1, 2), 160);
+        assert!(state.move_generation_stack.is_empty());
+    }
+
     #[test]
     fn test_generate_energy_distributions() {
         // 1 pokemon, 1 head
@@ -1758,11 +1921,7 @@ mod test {
         // The first mutation (0 heads, immediate tails) should still do 30 damage
         let mut rng = StdRng::seed_from_u64(0);
         let mut state = State::default();
-        let action = Action {
-            actor: 0,
-            action: SimpleAction::Attack(0),
-            is_stack: false,
-        };
+        let action = Action::new(0, SimpleAction::Attack(0), false);
 
         // Set up Oricorio (with Safeguard ability) as the opponent's active
         let oricorio = get_card_by_enum(CardId::A3066Oricorio); // 70 HP, Safeguard ability
@@ -1786,4 +1945,48 @@ mod test {
         // Verify Oricorio did NOT take damage
         assert_eq!(state.get_active(1).remaining_hp, 70);
     }
+
+    #[test]
+    fn test_primal_wingbeat_heads_returns_active_and_requires_promotion() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+        let attacker = get_card_by_enum(CardId::A1210Aerodactyl);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker, false));
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut defender = to_playable_card(&ivysaur, false);
+        defender.cards_behind = vec![bulbasaur.clone()];
+        defender.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        state.in_play_pokemon[1][0] = Some(defender);
+        state.in_play_pokemon[1][2] = Some(to_playable_card(&bulbasaur, false));
+
+        let (_, mut lazy_mutations) = primal_wingbeat();
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+
+        assert!(state.in_play_pokemon[1][0].is_none());
+        assert!(state.decks[1].cards.contains(&ivysaur));
+        assert!(state.decks[1].cards.contains(&bulbasaur));
+        assert_eq!(state.discard_energies[1].len(), 2);
+        assert_eq!(
+            state.move_generation_stack.last(),
+            Some(&(1, vec![SimpleAction::Activate { in_play_idx: 2 }]))
+        );
+    }
+
+    #[test]
+    fn test_primal_wingbeat_without_bench_wins_the_game() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let action = Action::new(0, SimpleAction::Attack(0), false);
+        let attacker = get_card_by_enum(CardId::A1210Aerodactyl);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker, false));
+        let defender = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&defender, false));
+
+        let (_, mut lazy_mutations) = primal_wingbeat();
+        lazy_mutations.remove(0)(&mut rng, &mut state, &action);
+
+        assert_eq!(state.winner, Some(GameOutcome::Win(0)));
+    }
 }
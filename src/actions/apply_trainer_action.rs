@@ -6,24 +6,25 @@ use rand::rngs::StdRng;
 use crate::{
     actions::{
         mutations::doutcome,
+        pending_choice::PendingChoice,
         shared_mutations::{
-            gladion_search_outcomes, pokemon_search_outcomes,
+            discard_tools, forced_switch, gladion_search_outcomes, pokemon_search_outcomes,
             pokemon_search_outcomes_with_filter_multiple,
         },
     },
     card_ids::CardId,
-    card_logic::can_rare_candy_evolve,
+    card_logic::{can_rare_candy_evolve, is_mallow_target},
     combinatorics::generate_combinations,
     effects::TurnEffect,
     hooks::{get_stage, is_ultra_beast},
     models::{Card, EnergyType, TrainerCard},
-    state::GameOutcome,
     tool_ids::ToolId,
     State,
 };
 
 use super::{
     apply_action_helpers::{Mutations, Probabilities},
+    coin_flips::flip_until_tails_outcomes,
     Action, SimpleAction,
 };
 
@@ -55,6 +56,8 @@ pub fn forecast_trainer_action(
         CardId::A2b070PokemonCenterLady | CardId::A2b089PokemonCenterLady => {
             doutcome(pokemon_center_lady_effect)
         }
+        CardId::A3151Guzma | CardId::A3193Guzma | CardId::A3208Guzma => doutcome(guzma_effect),
+        CardId::A3154Mallow | CardId::A3196Mallow => doutcome(mallow_effect),
         CardId::A3155Lillie
         | CardId::A3197Lillie
         | CardId::A3209Lillie
@@ -89,6 +92,9 @@ pub fn forecast_trainer_action(
         CardId::A2150Cyrus | CardId::A2190Cyrus | CardId::A4b326Cyrus | CardId::A4b327Cyrus => {
             doutcome(cyrus_effect)
         }
+        CardId::A2154Dawn | CardId::A2194Dawn | CardId::A4b342Dawn | CardId::A4b343Dawn => {
+            doutcome(dawn_effect)
+        }
         CardId::A2155Mars | CardId::A2195Mars | CardId::A4b344Mars | CardId::A4b345Mars => {
             doutcome(mars_effect)
         }
@@ -106,6 +112,7 @@ pub fn forecast_trainer_action(
         CardId::A3a067Gladion | CardId::A3a081Gladion => {
             gladion_search_outcomes(acting_player, state)
         }
+        CardId::A3a068Looker | CardId::A3a082Looker => doutcome(looker_effect),
         CardId::A3a069Lusamine
         | CardId::A3a083Lusamine
         | CardId::A4b350Lusamine
@@ -151,22 +158,32 @@ fn pokemon_center_lady_effect(_: &mut StdRng, state: &mut State, action: &Action
     debug!("Pokemon Center Lady: Healing 30 damage and curing status conditions");
     let possible_moves = state
         .enumerate_in_play_pokemon(action.actor)
+        .filter(|(_, pokemon)| pokemon.is_damaged() || pokemon.has_status_condition())
         .map(|(i, _)| SimpleAction::Heal {
             in_play_idx: i,
             amount: 30,
             cure_status: true,
         })
         .collect::<Vec<_>>();
-    if !possible_moves.is_empty() {
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
-    }
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(possible_moves));
+}
+
+fn mallow_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Heal all damage from 1 of your Shiinotic or Tsareena. If you do, discard all Energy from that Pokémon.
+    let possible_moves = state
+        .enumerate_damaged_pokemon(action.actor)
+        .filter(|(_, x)| is_mallow_target(x))
+        .map(|(i, _)| SimpleAction::FullHeal {
+            in_play_idx: i,
+            discard_energies: true,
+        })
+        .collect::<Vec<_>>();
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(possible_moves));
 }
 
 fn lillie_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     let possible_moves = state
-        .enumerate_in_play_pokemon(action.actor)
+        .enumerate_damaged_pokemon(action.actor)
         .filter(|(_, x)| get_stage(x) == 2)
         .map(|(i, _)| SimpleAction::Heal {
             in_play_idx: i,
@@ -174,18 +191,14 @@ fn lillie_effect(_: &mut StdRng, state: &mut State, action: &Action) {
             cure_status: false,
         })
         .collect::<Vec<_>>();
-    if !possible_moves.is_empty() {
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
-    }
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(possible_moves));
 }
 
 fn potion_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     inner_healing_effect(rng, state, action, 20, None);
 }
 
-// Queues up the decision of healing an in_play pokemon that matches energy (if None, then any)
+// Queues up the decision of healing a damaged in_play pokemon that matches energy (if None, then any)
 fn inner_healing_effect(
     _: &mut StdRng,
     state: &mut State,
@@ -194,49 +207,41 @@ fn inner_healing_effect(
     energy: Option<EnergyType>,
 ) {
     let possible_moves = state
-        .enumerate_in_play_pokemon(action.actor)
-        .filter(|(_, x)| energy.is_none() || x.get_energy_type() == Some(EnergyType::Grass))
+        .enumerate_damaged_pokemon(action.actor)
+        .filter(|(_, x)| energy.is_none() || x.get_energy_type() == energy)
         .map(|(i, _)| SimpleAction::Heal {
             in_play_idx: i,
             amount,
             cure_status: false,
         })
         .collect::<Vec<_>>();
-    if !possible_moves.is_empty() {
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
-    }
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(possible_moves));
 }
 
-// Will return 6 outputs, one that attaches no energy, one that
-//  queues decision of attaching 1 energy to in_play waters.
 fn misty_outcomes() -> (Probabilities, Mutations) {
-    // probabilistic attach energy to water pokemon
-    // 50% no energy, 25% 1 energy, 12.5% 2 energy, 6.75% 3 energy, 3.125% 4 energy, 1.5625% 5 energy
-    let probabilities = vec![0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625];
-    let mut outcomes: Mutations = vec![];
-    for j in 0..6 {
-        outcomes.push(Box::new({
-            move |_, state, action| {
-                // For each in_play water pokemon
-                let possible_moves = state
-                    .enumerate_in_play_pokemon(action.actor)
-                    .filter(|(_, x)| x.get_energy_type() == Some(EnergyType::Water))
-                    .map(|(i, _)| SimpleAction::Attach {
-                        attachments: vec![(j, EnergyType::Water, i)],
-                        is_turn_energy: false,
-                    })
-                    .collect::<Vec<_>>();
-                if !possible_moves.is_empty() {
-                    state
-                        .move_generation_stack
-                        .push((action.actor, possible_moves));
-                }
+    // Choose 1 of your [W] Pokémon, and flip a coin until you get tails. For each heads,
+    // take a [W] Energy from your Energy Zone and attach it to that Pokémon.
+    flip_until_tails_outcomes(5, |heads| {
+        Box::new(move |_, state, action| {
+            debug!("Misty: flipped {heads} heads");
+            if heads == 0 {
+                return;
             }
-        }));
-    }
-    (probabilities, outcomes)
+            let targets = state
+                .enumerate_in_play_pokemon(action.actor)
+                .filter(|(_, x)| x.get_energy_type() == Some(EnergyType::Water))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            state.push_pending_choice(
+                action.actor,
+                PendingChoice::AttachEnergy {
+                    energy: EnergyType::Water,
+                    amount: heads,
+                    targets,
+                },
+            );
+        })
+    })
 }
 
 // Remember to implement these in the main controller / hooks.
@@ -249,46 +254,55 @@ fn leaf_effect(_: &mut StdRng, state: &mut State, _: &Action) {
 
 fn sabrina_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Switch out your opponent's Active Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)
-    let opponent_player = (action.actor + 1) % 2;
-    let possible_moves = state
-        .enumerate_bench_pokemon(opponent_player)
-        .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
-        .collect::<Vec<_>>();
-    state
-        .move_generation_stack
-        .push((opponent_player, possible_moves));
+    let opponent_player = State::opponent_of(action.actor);
+    forced_switch(state, opponent_player, None);
+}
+
+fn looker_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Your opponent reveals all of the Supporter cards in their deck.
+    // Players here see the whole State, so revealing changes nothing.
+    let opponent = State::opponent_of(action.actor);
+    let supporters: Vec<&Card> = state.decks[opponent]
+        .cards
+        .iter()
+        .filter(|card| card.is_support())
+        .collect();
+    debug!("Looker: Player {opponent} reveals the Supporters in their deck: {supporters:?}");
+}
+
+fn guzma_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Discard all Pokémon Tool cards attached to each of your opponent's Pokémon.
+    let opponent_player = State::opponent_of(action.actor);
+    let targets: Vec<(usize, usize)> = state
+        .enumerate_in_play_pokemon(opponent_player)
+        .filter(|(_, x)| x.has_tool_attached())
+        .map(|(i, _)| (opponent_player, i))
+        .collect();
+    discard_tools(state, &targets);
 }
 
 fn repel_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Switch out your opponent's Active Basic Pokémon to the Bench. (Your opponent chooses the new Active Pokémon.)
-    let opponent_player = (action.actor + 1) % 2;
-    let possible_moves = state
-        .enumerate_bench_pokemon(opponent_player)
-        .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
-        .collect::<Vec<_>>();
-    state
-        .move_generation_stack
-        .push((opponent_player, possible_moves));
+    let opponent_player = State::opponent_of(action.actor);
+    forced_switch(state, opponent_player, None);
 }
 
 fn cyrus_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Switch 1 of your opponent's Pokemon that has damage on it to the Active Spot.
-    let opponent_player = (action.actor + 1) % 2;
+    let opponent_player = State::opponent_of(action.actor);
     let possible_moves = state
         .enumerate_bench_pokemon(opponent_player)
         .filter(|(_, x)| x.is_damaged())
-        .map(|(in_play_idx, _)| SimpleAction::Activate { in_play_idx })
+        .map(|(in_play_idx, _)| SimpleAction::ActivateOpponent { in_play_idx })
         .collect::<Vec<_>>();
-    state
-        .move_generation_stack
-        .push((opponent_player, possible_moves));
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(possible_moves));
 }
 
 fn mars_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     // Your opponent shuffles their hand into their deck and draws a card for each of their remaining points needed to win.
-    let opponent_player = (action.actor + 1) % 2;
+    let opponent_player = State::opponent_of(action.actor);
     let opponent_points = state.points[opponent_player];
-    let cards_to_draw = (3 - opponent_points) as usize;
+    let cards_to_draw = state.points_to_win.saturating_sub(opponent_points) as usize;
 
     debug!(
         "Mars: Opponent has {} points, shuffling hand and drawing {} cards",
@@ -321,54 +335,32 @@ fn red_effect(_: &mut StdRng, state: &mut State, _: &Action) {
 
 fn koga_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Put your Muk or Weezing in the Active Spot into your hand.
-    let active_pokemon = state.in_play_pokemon[action.actor][0]
-        .as_ref()
-        .expect("Active Pokemon should be there if Koga is played");
-    let mut cards_to_collect = active_pokemon.cards_behind.clone();
-    cards_to_collect.push(active_pokemon.card.clone());
-    state.hands[action.actor].extend(cards_to_collect);
-    // Energy dissapears
-    state.in_play_pokemon[action.actor][0] = None;
-
-    // if no bench pokemon, finish game as a loss
-    let bench_pokemon = state.enumerate_bench_pokemon(action.actor).count();
-    if bench_pokemon == 0 {
-        debug!("Player lost due to no bench pokemon after Koga");
-        state.winner = Some(GameOutcome::Win((action.actor + 1) % 2))
-    } else {
-        // else force current_player to promote one of their bench pokemon
-        let possible_moves = state
-            .enumerate_bench_pokemon(action.actor)
-            .map(|(i, _)| SimpleAction::Activate { in_play_idx: i })
-            .collect::<Vec<_>>();
-        state
-            .move_generation_stack
-            .push((action.actor, possible_moves));
-    }
+    // can_play_koga requires a Benched Pokémon, so there is always one to promote.
+    state.return_to_hand(action.actor, 0);
 }
 
 // TODO: Problem. With doing 1.0, we are basically giving bots the ability to see the cards in deck.
 // TODO: In theory this should give a probability distribution over cards in deck.
 fn professor_oak_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     // Draw 2 cards.
-    for _ in 0..2 {
-        state.maybe_draw_card(action.actor);
-    }
+    state.draw_cards(action.actor, 2);
 }
 
 // TODO: Actually use distribution of possibilities to capture probabilities
 // of pulling the different psychic left in deck vs pushing an item to the bottom.
 fn mythical_slab_effect(_: &mut StdRng, state: &mut State, action: &Action) {
-    // Look at the top card of your deck. If that card is a Psychic Pokemon,\n        put it in your hand. If it is not a Psychic Pokemon, put it on the\n        bottom of your deck.
-    if let Some(card) = state.decks[action.actor].cards.first() {
-        if card.is_basic() {
-            state.hands[action.actor].push(card.clone());
-            state.decks[action.actor].cards.remove(0);
-        } else {
-            let card = state.decks[action.actor].cards.remove(0);
-            state.decks[action.actor].cards.push(card);
-        }
-    } // else do nothing
+    // Look at the top card of your deck. If that card is a [P] Pokémon, put it into your hand.
+    // If it is not a [P] Pokémon, put it on the bottom of your deck.
+    let Some(card) = state.decks[action.actor].draw() else {
+        return;
+    };
+    if card.get_type() == Some(EnergyType::Psychic) {
+        debug!("Mythical Slab: Putting {card:?} into hand");
+        state.hands[action.actor].push(card);
+    } else {
+        debug!("Mythical Slab: Putting {card:?} on the bottom of the deck");
+        state.decks[action.actor].cards.push(card);
+    }
 }
 
 // Here we will simplify the output possibilities, counting with the fact that value functions
@@ -376,14 +368,12 @@ fn mythical_slab_effect(_: &mut StdRng, state: &mut State, action: &Action) {
 fn red_card_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     // Your opponent shuffles their hand into their deck and draws 3 cards.
     let acting_player = action.actor;
-    let opponent = (acting_player + 1) % 2;
+    let opponent = State::opponent_of(acting_player);
     let opponent_hand = &mut state.hands[opponent];
     let opponent_deck = &mut state.decks[opponent];
     opponent_deck.cards.append(opponent_hand);
     opponent_deck.shuffle(false, rng);
-    for _ in 0..3 {
-        state.maybe_draw_card(opponent);
-    }
+    state.draw_cards(opponent, 3);
 }
 
 // Give the choice to the player to attach a tool to one of their pokemon.
@@ -397,7 +387,7 @@ fn attach_tool(_: &mut StdRng, state: &mut State, action: &Action) {
                 tool_id,
             })
             .collect::<Vec<_>>();
-        state.move_generation_stack.push((action.actor, choices));
+        state.push_pending_choice(action.actor, PendingChoice::SelectTarget(choices));
     } else {
         panic!("Tool should have been played");
     }
@@ -418,11 +408,10 @@ fn rare_candy_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         })
         .collect();
 
-    if !possible_candy_evolutions.is_empty() {
-        state
-            .move_generation_stack
-            .push((player, possible_candy_evolutions));
-    }
+    state.push_pending_choice(
+        player,
+        PendingChoice::SelectTarget(possible_candy_evolutions),
+    );
 }
 
 /// Queue the decision for user to select which Pokemon from hand to swap
@@ -436,22 +425,37 @@ fn pokemon_communication_effect(_: &mut StdRng, state: &mut State, action: &Acti
         })
         .collect();
 
-    if !possible_swaps.is_empty() {
-        state.move_generation_stack.push((player, possible_swaps));
-    }
+    state.push_pending_choice(player, PendingChoice::SelectTarget(possible_swaps));
 }
 
 fn elemental_switch_effect(_: &mut StdRng, state: &mut State, action: &Action) {
-    let player = action.actor;
+    // Move a [R], [W], or [L] Energy from 1 of your Benched Pokémon to your Active Pokémon.
+    let allowed_types = [EnergyType::Fire, EnergyType::Water, EnergyType::Lightning];
+    queue_bench_to_active_energy_moves(state, action.actor, |energy| {
+        allowed_types.contains(&energy)
+    });
+}
+
+fn dawn_effect(_: &mut StdRng, state: &mut State, action: &Action) {
+    // Move an Energy from 1 of your Benched Pokémon to your Active Pokémon.
+    queue_bench_to_active_energy_moves(state, action.actor, |_| true);
+}
+
+/// Queues a choice of which (Benched Pokémon, energy type) to move to the Active Pokémon,
+/// among the energies accepted by `filter`.
+fn queue_bench_to_active_energy_moves(
+    state: &mut State,
+    player: usize,
+    filter: impl Fn(EnergyType) -> bool,
+) {
     if state.maybe_get_active(player).is_none() {
         return;
     }
-    let allowed_types = [EnergyType::Fire, EnergyType::Water, EnergyType::Lightning];
     let mut possible_transfers = Vec::new();
 
     for (from_idx, pokemon) in state.enumerate_bench_pokemon(player) {
         for &energy in &pokemon.attached_energy {
-            if allowed_types.contains(&energy) {
+            if filter(energy) {
                 let move_action = SimpleAction::MoveEnergy {
                     from_in_play_idx: from_idx,
                     to_in_play_idx: 0,
@@ -464,17 +468,13 @@ fn elemental_switch_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         }
     }
 
-    if !possible_transfers.is_empty() {
-        state
-            .move_generation_stack
-            .push((player, possible_transfers));
-    }
+    state.push_pending_choice(player, PendingChoice::SelectTarget(possible_transfers));
 }
 
 /// Queue the decision for user to select which Supporter from opponent's hand to shuffle
 fn silver_effect(_: &mut StdRng, state: &mut State, action: &Action) {
     let player = action.actor;
-    let opponent = (player + 1) % 2;
+    let opponent = State::opponent_of(player);
     let possible_shuffles: Vec<SimpleAction> = state.hands[opponent]
         .iter()
         .filter(|card| card.is_support())
@@ -483,11 +483,7 @@ fn silver_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         })
         .collect();
 
-    if !possible_shuffles.is_empty() {
-        state
-            .move_generation_stack
-            .push((player, possible_shuffles));
-    }
+    state.push_pending_choice(player, PendingChoice::SelectTarget(possible_shuffles));
 }
 
 /// Queue the decision for user to select which Ultra Beast to attach energies to
@@ -504,21 +500,12 @@ fn lusamine_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         })
         .collect();
 
-    if !possible_attachments.is_empty() {
-        state
-            .move_generation_stack
-            .push((player, possible_attachments));
-    }
+    state.push_pending_choice(player, PendingChoice::SelectTarget(possible_attachments));
 }
 
 fn lyra_effect(_: &mut StdRng, state: &mut State, action: &Action) {
-    let possible_activations = state
-        .enumerate_bench_pokemon(action.actor)
-        .map(|(idx, _)| SimpleAction::Activate { in_play_idx: idx })
-        .collect();
-    state
-        .move_generation_stack
-        .push((action.actor, possible_activations))
+    // Switch your Active Pokémon that has damage on it with 1 of your Benched Pokémon.
+    state.push_pending_choice(action.actor, PendingChoice::PromoteActive);
 }
 
 fn eevee_bag_effect(_: &mut StdRng, state: &mut State, action: &Action) {
@@ -526,7 +513,7 @@ fn eevee_bag_effect(_: &mut StdRng, state: &mut State, action: &Action) {
         SimpleAction::ApplyEeveeBagDamageBoost,
         SimpleAction::HealAllEeveeEvolutions,
     ];
-    state.move_generation_stack.push((action.actor, choices));
+    state.push_pending_choice(action.actor, PendingChoice::SelectTarget(choices));
 }
 
 fn flame_patch_effect(_: &mut StdRng, state: &mut State, action: &Action) {
@@ -549,7 +536,7 @@ fn flame_patch_effect(_: &mut StdRng, state: &mut State, action: &Action) {
 fn copycat_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     // Shuffle your hand into your deck. Draw a card for each card in your opponent's hand.
     let player = action.actor;
-    let opponent = (player + 1) % 2;
+    let opponent = State::opponent_of(player);
 
     // Count opponent's hand size before shuffling
     let opponent_hand_size = state.hands[opponent].len();
@@ -564,15 +551,13 @@ fn copycat_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     state.decks[player].shuffle(false, rng);
 
     // Draw cards equal to opponent's hand size
-    for _ in 0..opponent_hand_size {
-        state.maybe_draw_card(player);
-    }
+    state.draw_cards(player, opponent_hand_size);
 }
 
 fn iono_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     // Each player shuffles the cards in their hand into their deck, then draws that many cards.
     let player = action.actor;
-    let opponent = (player + 1) % 2;
+    let opponent = State::opponent_of(player);
 
     // Count each player's hand size before shuffling
     let player_hand_size = state.hands[player].len();
@@ -594,12 +579,8 @@ fn iono_effect(rng: &mut StdRng, state: &mut State, action: &Action) {
     state.decks[opponent].shuffle(false, rng);
 
     // Each player draws the same number of cards they had
-    for _ in 0..player_hand_size {
-        state.maybe_draw_card(player);
-    }
-    for _ in 0..opponent_hand_size {
-        state.maybe_draw_card(opponent);
-    }
+    state.draw_cards(player, player_hand_size);
+    state.draw_cards(opponent, opponent_hand_size);
 }
 
 pub fn may_effect(acting_player: usize, state: &State) -> (Probabilities, Mutations) {
@@ -629,12 +610,12 @@ pub fn may_effect(acting_player: usize, state: &State) -> (Probabilities, Mutati
                 .expect("Pokemon should be in deck");
             state.transfer_card_from_deck_to_hand(action.actor, &pokemon);
             // Queue shuffling that Pokemon back into deck
-            state.move_generation_stack.push((
+            state.push_pending_choice(
                 action.actor,
-                vec![SimpleAction::ShufflePokemonIntoDeck {
+                PendingChoice::SelectTarget(vec![SimpleAction::ShufflePokemonIntoDeck {
                     hand_pokemon: vec![pokemon],
-                }],
-            ));
+                }]),
+            );
         }));
         return (probabilities, outcomes);
     }
@@ -660,9 +641,7 @@ pub fn may_effect(acting_player: usize, state: &State) -> (Probabilities, Mutati
                     hand_pokemon: combo,
                 })
                 .collect();
-            state
-                .move_generation_stack
-                .push((action.actor, shuffle_choices));
+            state.push_pending_choice(action.actor, PendingChoice::SelectTarget(shuffle_choices));
         }));
     }
 
@@ -679,3 +658,21 @@ fn lisia_effect(acting_player: usize, state: &State) -> (Probabilities, Mutation
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::get_card_by_enum;
+
+    #[test]
+    fn test_misty_forecast_has_one_outcome_per_heads_count() {
+        let misty = get_card_by_enum(CardId::A1220Misty).as_trainer();
+        let (probabilities, mutations) = forecast_trainer_action(0, &State::default(), &misty);
+
+        assert_eq!(
+            probabilities,
+            vec![0.5, 0.25, 0.125, 0.0625, 0.03125, 0.03125]
+        );
+        assert_eq!(mutations.len(), probabilities.len());
+    }
+}
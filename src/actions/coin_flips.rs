@@ -0,0 +1,51 @@
+use rand::Rng;
+
+use super::apply_action_helpers::{Mutation, Mutations, Probabilities};
+
+/// Flips a coin until it lands on tails. Returns the number of heads. Effects resolved during
+/// search should use `flip_until_tails_outcomes` instead, so that the chance is forecast.
+pub fn flip_until_tails(rng: &mut impl Rng) -> u32 {
+    let mut heads = 0;
+    while rng.gen_bool(0.5) {
+        heads += 1;
+    }
+    heads
+}
+
+/// Forecasts a "flip a coin until you get tails" effect with one outcome per number of heads,
+/// built by `per_heads`. The geometric distribution is truncated at `max_heads`, whose
+/// outcome also covers any longer streak so that probabilities add up to 1.
+pub(crate) fn flip_until_tails_outcomes(
+    max_heads: u32,
+    per_heads: impl Fn(u32) -> Mutation,
+) -> (Probabilities, Mutations) {
+    // P(k heads) = (1/2)^(k+1), and P(at least max_heads heads) = (1/2)^max_heads
+    let mut probabilities: Probabilities = (0..max_heads)
+        .map(|heads| 0.5_f64.powi(heads as i32 + 1))
+        .collect();
+    probabilities.push(0.5_f64.powi(max_heads as i32));
+    let mutations = (0..=max_heads).map(per_heads).collect();
+    (probabilities, mutations)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_flip_until_tails_is_seeded() {
+        let heads: Vec<u32> = (0..6)
+            .map(|seed| flip_until_tails(&mut StdRng::seed_from_u64(seed)))
+            .collect();
+        assert_eq!(heads, vec![0, 0, 6, 7, 0, 3]);
+    }
+
+    #[test]
+    fn test_flip_until_tails_outcomes_sum_to_one() {
+        let (probabilities, mutations) = flip_until_tails_outcomes(3, |_| Box::new(|_, _, _| {}));
+        assert_eq!(probabilities, vec![0.5, 0.25, 0.125, 0.125]);
+        assert_eq!(mutations.len(), 4);
+    }
+}
@@ -3,7 +3,9 @@ mod apply_action;
 mod apply_action_helpers;
 mod apply_attack_action;
 mod apply_trainer_action;
+mod coin_flips;
 mod mutations;
+mod pending_choice;
 mod shared_mutations;
 mod types;
 
@@ -11,5 +13,7 @@ pub(crate) use apply_action::apply_action;
 pub(crate) use apply_action::apply_evolve;
 pub(crate) use apply_action::forecast_action;
 pub use apply_trainer_action::may_effect;
+pub use coin_flips::flip_until_tails;
+pub use pending_choice::PendingChoice;
 pub use types::Action;
 pub use types::SimpleAction;
@@ -59,7 +59,7 @@ where
             let active = state.get_active(action.actor);
             let attack = active.card.get_attacks()[attack_index].clone();
             let damage = attack.fixed_damage;
-            let target_player = (action.actor + 1) % 2;
+            let target_player = State::opponent_of(action.actor);
             handle_damage(
                 state,
                 (action.actor, 0),
@@ -109,7 +109,7 @@ where
     Box::new({
         move |rng, state, action| {
             additional_effect(rng, state, action);
-            let opponent = (action.actor + 1) % 2;
+            let opponent = State::opponent_of(action.actor);
             let targets: Vec<(u32, usize, usize)> = targets
                 .iter()
                 .map(|(damage, in_play_idx)| (*damage, opponent, *in_play_idx))
@@ -123,7 +123,7 @@ where
 pub(crate) fn build_status_effect(status: StatusCondition) -> FnMutation {
     Box::new({
         move |_, state: &mut State, action: &Action| {
-            let opponent = (action.actor + 1) % 2;
+            let opponent = State::opponent_of(action.actor);
             let opponent_active = state.get_active_mut(opponent);
             opponent_active.apply_status_condition(status);
         }
@@ -145,11 +145,7 @@ mod test {
     fn test_build_status_effect() {
         let mut rng = StdRng::seed_from_u64(0);
         let mut state = State::default();
-        let action = Action {
-            actor: 0,
-            action: SimpleAction::EndTurn,
-            is_stack: false,
-        };
+        let action = Action::new(0, SimpleAction::EndTurn, false);
         let bulbasuar = get_card_by_enum(CardId::A1001Bulbasaur);
         state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasuar, false));
         let effect = build_status_effect(StatusCondition::Asleep);
@@ -161,11 +157,7 @@ mod test {
     fn test_arceus_avoids_status() {
         let mut rng = StdRng::seed_from_u64(0);
         let mut state = State::default();
-        let action = Action {
-            actor: 0,
-            action: SimpleAction::EndTurn,
-            is_stack: false,
-        };
+        let action = Action::new(0, SimpleAction::EndTurn, false);
         let arceus = get_card_by_enum(CardId::A2a071ArceusEx);
         state.in_play_pokemon[1][0] = Some(to_playable_card(&arceus, false));
         let effect = build_status_effect(StatusCondition::Asleep);
@@ -0,0 +1,104 @@
+use crate::{
+    actions::SimpleAction,
+    models::{Card, EnergyType},
+    State,
+};
+
+/// The kind of decision a multi-step effect leaves pending for a player. Effects queue
+/// these with `State::push_pending_choice`, which expands them into the concrete
+/// `SimpleAction`s placed on the `move_generation_stack`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingChoice {
+    /// Choose a Benched Pokémon to become the new Active Pokémon.
+    PromoteActive,
+    /// Choose one of `targets` (in_play_idx) to receive `amount` energies of `energy`.
+    AttachEnergy {
+        energy: EnergyType,
+        amount: u32,
+        targets: Vec<usize>,
+    },
+    /// Choose one of the given already-targeted actions (e.g. which Pokémon to heal).
+    SelectTarget(Vec<SimpleAction>),
+    /// Choose one of the given cards in hand to discard.
+    Discard(Vec<Card>),
+}
+
+impl PendingChoice {
+    /// The actions `player` may pick from to resolve this choice.
+    pub fn to_actions(&self, state: &State, player: usize) -> Vec<SimpleAction> {
+        match self {
+            PendingChoice::PromoteActive => state
+                .enumerate_bench_pokemon(player)
+                .map(|(in_play_idx, _)| SimpleAction::Activate { in_play_idx })
+                .collect(),
+            PendingChoice::AttachEnergy {
+                energy,
+                amount,
+                targets,
+            } => targets
+                .iter()
+                .map(|&in_play_idx| SimpleAction::Attach {
+                    attachments: vec![(*amount, *energy, in_play_idx)],
+                    is_turn_energy: false,
+                })
+                .collect(),
+            PendingChoice::SelectTarget(actions) => actions.clone(),
+            PendingChoice::Discard(cards) => cards
+                .iter()
+                .map(|card| SimpleAction::DiscardOwnCard { card: card.clone() })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card_ids::CardId, database::get_card_by_enum, models::PlayedCard};
+
+    fn played(card_id: CardId) -> Option<PlayedCard> {
+        let card = get_card_by_enum(card_id);
+        Some(PlayedCard::new(card, 70, 70, vec![], false, vec![]))
+    }
+
+    #[test]
+    fn test_queued_attach_energy_generates_one_attach_per_target() {
+        let mut state = State::default();
+        state.turn_count = 3;
+        state.in_play_pokemon[0][0] = played(CardId::A1053Squirtle);
+        state.in_play_pokemon[0][2] = played(CardId::A1053Squirtle);
+        state.push_pending_choice(
+            0,
+            PendingChoice::AttachEnergy {
+                energy: EnergyType::Water,
+                amount: 2,
+                targets: vec![0, 2],
+            },
+        );
+
+        let (actor, actions) = crate::move_generation::generate_possible_actions(&state);
+        assert_eq!(actor, 0);
+        let simple_actions: Vec<SimpleAction> = actions.into_iter().map(|a| a.action).collect();
+        assert_eq!(
+            simple_actions,
+            vec![
+                SimpleAction::Attach {
+                    attachments: vec![(2, EnergyType::Water, 0)],
+                    is_turn_energy: false,
+                },
+                SimpleAction::Attach {
+                    attachments: vec![(2, EnergyType::Water, 2)],
+                    is_turn_energy: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_pending_choice_skips_choices_without_options() {
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = played(CardId::A1053Squirtle);
+        state.push_pending_choice(0, PendingChoice::PromoteActive);
+        assert!(state.move_generation_stack.is_empty());
+    }
+}
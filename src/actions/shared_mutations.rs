@@ -1,14 +1,19 @@
 use log::debug;
+use rand::rngs::StdRng;
 use std::cmp::min;
 
 use crate::{
     actions::{
-        apply_action_helpers::{Mutations, Probabilities},
+        apply_action_helpers::{handle_knockouts, Mutations, Probabilities},
         mutations::doutcome,
+        PendingChoice, SimpleAction,
     },
+    card_logic::is_gladion_target,
     combinatorics::generate_combinations,
-    hooks::to_playable_card,
+    effects::CardEffect,
+    hooks::{on_detach_tool, to_playable_card},
     models::{Card, EnergyType},
+    state::GameOutcome,
     State,
 };
 
@@ -43,9 +48,8 @@ pub(crate) fn gladion_search_outcomes(
     acting_player: usize,
     state: &State,
 ) -> (Probabilities, Mutations) {
-    pokemon_search_outcomes_with_filter(acting_player, state, move |card: &&Card| {
-        let name = card.get_name();
-        name == "Type: Null" || name == "Silvally"
+    pokemon_search_outcomes_with_filter(acting_player, state, |card: &&Card| {
+        is_gladion_target(card)
     })
 }
 
@@ -96,12 +100,21 @@ where
 
     for combo in draw_combinations {
         outcomes.push(Box::new(move |rng, state, action| {
-            // Transfer each Pokemon from the combination to hand
-            for pokemon in &combo {
-                state.transfer_card_from_deck_to_hand(action.actor, pokemon);
-            }
-
-            state.decks[action.actor].shuffle(false, rng);
+            // Transfer exactly the Pokemon in the combination to hand
+            let mut wanted = combo.clone();
+            let moved = state.search_deck_to_hand(
+                action.actor,
+                |card| match wanted.iter().position(|x| x == card) {
+                    Some(i) => {
+                        wanted.swap_remove(i);
+                        true
+                    }
+                    None => false,
+                },
+                combo.len(),
+                rng,
+            );
+            assert_eq!(moved, combo.len(), "Searched Pokemon should be in deck");
         }));
     }
 
@@ -133,15 +146,11 @@ pub(crate) fn search_and_bench_by_name(
         for i in 0..num_cards_in_deck {
             outcomes.push(Box::new(move |rng, state, action| {
                 // Check if there's bench space first
-                let bench_space = state.in_play_pokemon[action.actor]
-                    .iter()
-                    .position(|x| x.is_none());
-
-                if bench_space.is_none() {
+                let Some(bench_idx) = state.first_open_bench_slot(action.actor) else {
                     debug!("No bench space available, shuffling deck without placing card");
                     state.decks[action.actor].shuffle(false, rng);
                     return;
-                }
+                };
 
                 let card = state.decks[action.actor]
                     .cards
@@ -166,7 +175,6 @@ pub(crate) fn search_and_bench_by_name(
                 }
 
                 // Place on bench
-                let bench_idx = bench_space.unwrap();
                 let playable_card = to_playable_card(&card, true);
                 state.in_play_pokemon[action.actor][bench_idx] = Some(playable_card);
 
@@ -176,3 +184,57 @@ pub(crate) fn search_and_bench_by_name(
         (probabilities, outcomes)
     }
 }
+
+/// Switch out `player`'s Active Pokémon to the Bench (they choose the new Active Pokémon).
+/// If `effect` is given, the newly promoted Active Pokémon gets that CardEffect for the
+/// given duration (e.g. drag out and trap with CardEffect::NoRetreat).
+pub(crate) fn forced_switch(state: &mut State, player: usize, effect: Option<(CardEffect, u8)>) {
+    let Some((effect, duration)) = effect else {
+        state.push_pending_choice(player, PendingChoice::PromoteActive);
+        return;
+    };
+    let possible_moves = state
+        .enumerate_bench_pokemon(player)
+        .map(|(in_play_idx, _)| SimpleAction::ActivateWithEffect {
+            in_play_idx,
+            effect,
+            duration,
+        })
+        .collect::<Vec<_>>();
+    state.push_pending_choice(player, PendingChoice::SelectTarget(possible_moves));
+}
+
+/// Shuffles `player`'s Active Pokémon (with its evolution stack and Tool) back into their deck,
+/// discarding its energies. They must then promote a Benched Pokémon, or lose if they have none.
+pub(crate) fn shuffle_active_into_deck(rng: &mut StdRng, state: &mut State, player: usize) {
+    state.return_active_to_deck(player, rng);
+    if state.enumerate_bench_pokemon(player).next().is_none() {
+        debug!("Player {player} lost due to no bench pokemon");
+        state.winner = Some(GameOutcome::Win(State::opponent_of(player)));
+        return;
+    }
+    state.push_pending_choice(player, PendingChoice::PromoteActive);
+}
+
+/// Discards all tools attached to each of the given (player, in_play_idx) Pokémon. Losing an
+/// HP-boosting tool keeps the damage taken, so any Pokémon left at 0 HP is K.O.ed.
+pub(crate) fn discard_tools(state: &mut State, targets: &[(usize, usize)]) {
+    let mut knockouts = vec![];
+    for &(player, in_play_idx) in targets {
+        let pokemon = state.in_play_pokemon[player][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there if discarding its tools");
+        let tools = std::mem::take(&mut pokemon.attached_tools);
+        debug!("Discarding tools {tools:?} from player {player}'s Pokemon {in_play_idx}");
+        for tool_id in tools {
+            state.discard_piles[player].push(tool_id.to_card());
+            on_detach_tool(state, player, in_play_idx, tool_id);
+        }
+        if state.get_remaining_hp(player, in_play_idx) == 0 {
+            knockouts.push((player, in_play_idx));
+        }
+    }
+    if !knockouts.is_empty() {
+        handle_knockouts(state, knockouts, false);
+    }
+}
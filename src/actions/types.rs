@@ -1,4 +1,5 @@
 use crate::{
+    effects::CardEffect,
     models::{Card, EnergyType, TrainerCard},
     tool_ids::ToolId,
 };
@@ -8,13 +9,34 @@ use std::fmt;
 /// Main structure for following Game Tree design. Using "nesting" with a
 /// SimpleAction to share common fields here.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Action {
     pub actor: usize,
     pub action: SimpleAction,
     pub is_stack: bool,
+    /// Hint from move generation of how likely-good this action is, for stochastic players.
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+}
+
+impl Action {
+    /// Creates an action with the default (neutral) weight.
+    pub fn new(actor: usize, action: SimpleAction, is_stack: bool) -> Self {
+        Action {
+            actor,
+            action,
+            is_stack,
+            weight: default_weight(),
+        }
+    }
+}
+
+fn default_weight() -> f32 {
+    1.0
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum SimpleAction {
     DrawCard {
         amount: u8,
@@ -57,6 +79,11 @@ pub enum SimpleAction {
         amount: u32,
         cure_status: bool,
     },
+    /// Heal all damage from the in_play_idx pokemon (e.g. Mallow).
+    FullHeal {
+        in_play_idx: usize,
+        discard_energies: bool,
+    },
     ApplyDamage {
         attacking_ref: (usize, usize), // (attacking_player, attacking_pokemon_idx)
         targets: Vec<(u32, usize, usize)>, // Vec of (damage, target_player, in_play_idx)
@@ -66,6 +93,17 @@ pub enum SimpleAction {
     Activate {
         in_play_idx: usize,
     },
+    /// Like Activate, but the newly promoted Active also gets the given CardEffect
+    /// (see PlayedCard::add_effect for the duration semantics).
+    ActivateWithEffect {
+        in_play_idx: usize,
+        effect: CardEffect,
+        duration: u8,
+    },
+    /// Switch the opponent's in_play_idx pokemon with their active pokemon (e.g. Cyrus).
+    ActivateOpponent {
+        in_play_idx: usize,
+    },
     // Custom Mechanics:
     /// Pokemon Communication: swap a specific Pokemon from hand with a random Pokemon from deck
     CommunicatePokemon {
@@ -144,6 +182,13 @@ impl fmt::Display for SimpleAction {
                 amount,
                 cure_status,
             } => write!(f, "Heal({in_play_idx}, {amount}, cure:{cure_status})"),
+            SimpleAction::ActivateOpponent { in_play_idx } => {
+                write!(f, "ActivateOpponent({in_play_idx})")
+            }
+            SimpleAction::FullHeal {
+                in_play_idx,
+                discard_energies,
+            } => write!(f, "FullHeal({in_play_idx}, discard:{discard_energies})"),
             SimpleAction::ApplyDamage {
                 attacking_ref,
                 targets,
@@ -163,6 +208,14 @@ impl fmt::Display for SimpleAction {
                 )
             }
             SimpleAction::Activate { in_play_idx } => write!(f, "Activate({in_play_idx})"),
+            SimpleAction::ActivateWithEffect {
+                in_play_idx,
+                effect,
+                duration,
+            } => write!(
+                f,
+                "ActivateWithEffect({in_play_idx}, {effect:?}, {duration})"
+            ),
             SimpleAction::CommunicatePokemon { hand_pokemon } => {
                 write!(f, "CommunicatePokemon({hand_pokemon})")
             }
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum AttackId {
     A1003VenusaurMegaDrain,
     A1004VenusaurExGiantBloom,
@@ -59,6 +60,7 @@ pub enum AttackId {
     A1136GolurkDoubleLariat,
     A1142PrimeapeFightBack,
     A1149GolemDoubleEdge,
+    A1151CuboneGrowl,
     A1153MarowakExBonemerang,
     A1154HitmonleeStretchKick,
     A1163GrapploctKnockBack,
@@ -71,6 +73,7 @@ pub enum AttackId {
     A1196MeowthPayDay,
     A1201LickitungContinuousLick,
     A1203KangaskhanDizzyPunch,
+    A1210AerodactylPrimalWingbeat,
     A1213CinccinoDoTheWave,
     A1a001ExeggcuteGrowthSpurt,
     A1a002ExeggutorPsychic,
@@ -90,6 +93,7 @@ pub enum AttackId {
     A2023MagmarStoke,
     A2029InfernapeExFlareBlitz,
     A2035PiplupNap,
+    A2046GlaceonIceBeam,
     A2049PalkiaExDimensionalStorm,
     A2050ManaphyOceanicGift,
     A2053MagnezoneThunderBlast,
@@ -106,6 +110,7 @@ pub enum AttackId {
     A2131AmbipomDoubleHit,
     A2141ChatotFuryAttack,
     A2a001HeracrossSingleHornThrow,
+    A2a030ClefableMoonblast,
     A2a063SnorlaxCollapse,
     A2a057ProbopassExDefensiveUnit,
     A2a071ArceusExUltimateForce,
@@ -147,6 +152,7 @@ pub enum AttackId {
     A3a007PheromosaJumpBlues,
     A3a019TapuKokoExPlasmaHurricane,
     A3a033LycanrocExLycanfang,
+    A3a038AlolanPersianFakeOut,
     A3a042NihilegoNewWave,
     A3a043GuzzlordExGrindcore,
     A3a044Poipole2Step,
@@ -161,6 +167,8 @@ pub enum AttackId {
     A3b013IncineroarDarkestLariat,
     A3b014SalanditEmber,
     A3b020VanilluxeDoubleSpin,
+    A3b026JoltikJolt,
+    A3b047MawilePowerfulVise,
     A3b053DragoniteExGigaImpact,
     A3b055EeveeCollect,
     A3b057SnorlaxExFlopDownPunch,
@@ -170,7 +178,11 @@ pub enum AttackId {
     A4026NinetalesScorchingBreath,
     A4032MagbyToastyToss,
     A4053QwilfishPoisonSting,
+    A4059SuicuneCureStream,
+    A4078TogepiCharm,
     A4066PichuCracklyToss,
+    A4075SmoochumShiveryWave,
+    A4076JynxAttractSmack,
     A4077CleffaTwinklyCall,
     A4102HitmontopPiercingSpin,
     A4104PupitarGuardPress,
@@ -181,6 +193,7 @@ pub enum AttackId {
     A4120AbsolLeapOver,
     A4124SkarmoryExSteelWing,
     A4134EeveeFindAFriend,
+    A4144DunsparceSuddenFlash,
     A4146UrsaringSwingAround,
     A4149LugiaExElementalBlast,
     A4a010EnteiExBlazingBeatdown,
@@ -203,6 +216,7 @@ pub enum AttackId {
     B1109ChinglingJinglyNoise,
     B1121IndeedeeExPsychic,
     B1179GoodraSpiralDrain,
+    B1187MiltankBodySlam,
     B1150AbsolOminousClaw,
     B1151MegaAbsolExDarknessClaw,
     B1157HydreigonHyperRay,
@@ -272,6 +286,7 @@ lazy_static::lazy_static! {
         m.insert(("A1 136", 0), AttackId::A1136GolurkDoubleLariat);
         m.insert(("A1 142", 0), AttackId::A1142PrimeapeFightBack);
         m.insert(("A1 149", 0), AttackId::A1149GolemDoubleEdge);
+        m.insert(("A1 151", 0), AttackId::A1151CuboneGrowl);
         m.insert(("A1 153", 0), AttackId::A1153MarowakExBonemerang);
         m.insert(("A1 154", 0), AttackId::A1154HitmonleeStretchKick);
         m.insert(("A1 163", 0), AttackId::A1163GrapploctKnockBack);
@@ -284,10 +299,13 @@ lazy_static::lazy_static! {
         m.insert(("A1 196", 0), AttackId::A1196MeowthPayDay);
         m.insert(("A1 201", 0), AttackId::A1201LickitungContinuousLick);
         m.insert(("A1 203", 0), AttackId::A1203KangaskhanDizzyPunch);
+        m.insert(("A1 210", 0), AttackId::A1210AerodactylPrimalWingbeat);
+        m.insert(("A3a 098", 0), AttackId::A1210AerodactylPrimalWingbeat);
         m.insert(("A1 213", 0), AttackId::A1213CinccinoDoTheWave);
         // Full Arts A1
         m.insert(("A1 229", 0), AttackId::A1026PinsirDoubleHorn);
         m.insert(("A1 230", 0), AttackId::A1033CharmanderEmber);
+        m.insert(("A1 239", 0), AttackId::A1151CuboneGrowl);
         m.insert(("A1 233", 0), AttackId::A1078GyaradosHyperBeam);
         m.insert(("A1 234", 0), AttackId::A1079LaprasHydroPump);
         m.insert(("A1 236", 0), AttackId::A1117AlakazamPsychic);
@@ -342,6 +360,7 @@ lazy_static::lazy_static! {
         m.insert(("A2 023", 0), AttackId::A2023MagmarStoke);
         m.insert(("A2 029", 0), AttackId::A2029InfernapeExFlareBlitz);
         m.insert(("A2 035", 0), AttackId::A2035PiplupNap);
+        m.insert(("A2 046", 0), AttackId::A2046GlaceonIceBeam);
         m.insert(("A2 049", 1), AttackId::A2049PalkiaExDimensionalStorm);
         m.insert(("A2 050", 0), AttackId::A2050ManaphyOceanicGift);
         m.insert(("A2 053", 0), AttackId::A2053MagnezoneThunderBlast);
@@ -370,6 +389,7 @@ lazy_static::lazy_static! {
 
         // A2a
         m.insert(("A2a 001", 0), AttackId::A2a001HeracrossSingleHornThrow);
+        m.insert(("A2a 030", 0), AttackId::A2a030ClefableMoonblast);
         m.insert(("A2a 063", 0), AttackId::A2a063SnorlaxCollapse);
         m.insert(("A2a 057", 0), AttackId::A2a057ProbopassExDefensiveUnit);
         m.insert(("A2a 071", 0), AttackId::A2a071ArceusExUltimateForce);
@@ -436,6 +456,7 @@ lazy_static::lazy_static! {
         m.insert(("A3 212", 0), AttackId::A1003VenusaurMegaDrain);
         m.insert(("A3 214", 0), AttackId::A1a002ExeggutorPsychic);
         m.insert(("A3 217", 0), AttackId::A1055BlastoiseHydroPump);
+        m.insert(("A3 226", 0), AttackId::A1151CuboneGrowl);
         m.insert(("A3 230", 1), AttackId::A1004VenusaurExGiantBloom);
         m.insert(("A3 231", 0), AttackId::A1023ExeggutorExTropicalSwing);
         m.insert(("A3 232", 1), AttackId::A1056BlastoiseExHydroBazooka);
@@ -451,6 +472,7 @@ lazy_static::lazy_static! {
         m.insert(("A3a 007", 0), AttackId::A3a007PheromosaJumpBlues);
         m.insert(("A3a 019", 0), AttackId::A3a019TapuKokoExPlasmaHurricane);
         m.insert(("A3a 033", 0), AttackId::A3a033LycanrocExLycanfang);
+        m.insert(("A3a 038", 0), AttackId::A3a038AlolanPersianFakeOut);
         m.insert(("A3a 042", 0), AttackId::A3a042NihilegoNewWave);
         m.insert(("A3a 043", 0), AttackId::A3a043GuzzlordExGrindcore);
         m.insert(("A3a 044", 0), AttackId::A3a044Poipole2Step);
@@ -486,6 +508,8 @@ lazy_static::lazy_static! {
         m.insert(("A3b 013", 0), AttackId::A3b013IncineroarDarkestLariat);
         m.insert(("A3b 014", 0), AttackId::A3b014SalanditEmber);
         m.insert(("A3b 020", 0), AttackId::A3b020VanilluxeDoubleSpin);
+        m.insert(("A3b 026", 0), AttackId::A3b026JoltikJolt);
+        m.insert(("A3b 047", 0), AttackId::A3b047MawilePowerfulVise);
         m.insert(("A3b 053", 0), AttackId::A3b053DragoniteExGigaImpact);
         m.insert(("A3b 055", 0), AttackId::A3b055EeveeCollect);
         m.insert(("A3b 057", 0), AttackId::A3b057SnorlaxExFlopDownPunch);
@@ -509,6 +533,11 @@ lazy_static::lazy_static! {
         m.insert(("A4 026", 0), AttackId::A4026NinetalesScorchingBreath);
         m.insert(("A4 032", 0), AttackId::A4032MagbyToastyToss);
         m.insert(("A4 053", 0), AttackId::A4053QwilfishPoisonSting);
+        m.insert(("A4 059", 0), AttackId::A4059SuicuneCureStream);
+        m.insert(("A4 075", 0), AttackId::A4075SmoochumShiveryWave);
+        m.insert(("A4 076", 0), AttackId::A4076JynxAttractSmack);
+        m.insert(("A4 078", 0), AttackId::A4078TogepiCharm);
+        m.insert(("A4 173", 0), AttackId::A4078TogepiCharm);
         m.insert(("A4 066", 0), AttackId::A4066PichuCracklyToss);
         m.insert(("A4 077", 0), AttackId::A4077CleffaTwinklyCall);
         m.insert(("A4 102", 0), AttackId::A4102HitmontopPiercingSpin);
@@ -520,6 +549,7 @@ lazy_static::lazy_static! {
         m.insert(("A4 120", 0), AttackId::A4120AbsolLeapOver);
         m.insert(("A4 124", 0), AttackId::A4124SkarmoryExSteelWing);
         m.insert(("A4 134", 0), AttackId::A4134EeveeFindAFriend);
+        m.insert(("A4 144", 0), AttackId::A4144DunsparceSuddenFlash);
         m.insert(("A4 146", 0), AttackId::A4146UrsaringSwingAround);
         m.insert(("A4 149", 0), AttackId::A4149LugiaExElementalBlast);
         m.insert(("A4 166", 0), AttackId::A4032MagbyToastyToss);
@@ -611,6 +641,8 @@ lazy_static::lazy_static! {
         m.insert(("A4b 181", 0), AttackId::A3085CosmogTeleport);
         m.insert(("A4b 182", 0), AttackId::A3086CosmoemStiffen);
         m.insert(("A4b 183", 0), AttackId::A3086CosmoemStiffen);
+        m.insert(("A4b 194", 0), AttackId::A1151CuboneGrowl);
+        m.insert(("A4b 195", 0), AttackId::A1151CuboneGrowl);
         m.insert(("A4b 196", 0), AttackId::A1153MarowakExBonemerang);
         m.insert(("A4b 222", 0), AttackId::A3a033LycanrocExLycanfang);
         m.insert(("A4b 232", 0), AttackId::A4109CrobatExVenomousSlash);
@@ -670,6 +702,7 @@ lazy_static::lazy_static! {
         m.insert(("B1 157", 0), AttackId::B1157HydreigonHyperRay);
         m.insert(("B1 161", 0), AttackId::B1161MareaniePoisonSting);
         m.insert(("B1 179", 0), AttackId::B1179GoodraSpiralDrain);
+        m.insert(("B1 187", 0), AttackId::B1187MiltankBodySlam);
         m.insert(("B1 196", 0), AttackId::B1196SwabluSing);
         m.insert(("B1 232", 0), AttackId::B1050MagikarpWaterfallEvolution);
         m.insert(("B1 237", 0), AttackId::B1088LuxrayFlashImpact);
@@ -713,6 +746,7 @@ lazy_static::lazy_static! {
         m.insert(("P-A 018", 0), AttackId::A1003VenusaurMegaDrain);
         m.insert(("P-A 025", 0), AttackId::A1047MoltresExInfernoDance);
         m.insert(("P-A 029", 0), AttackId::A1055BlastoiseHydroPump);
+        m.insert(("P-A 030", 0), AttackId::A1151CuboneGrowl);
         m.insert(("P-A 031", 0), AttackId::A1213CinccinoDoTheWave);
         m.insert(("P-A 032", 0), AttackId::A1033CharmanderEmber);
         m.insert(("P-A 034", 0), AttackId::A2035PiplupNap);
@@ -130,6 +130,12 @@ fn print_card(enum_name: &str, card: &Card) {
                 "            weakness: {},",
                 to_rust_energy(pokemon_card.weakness)
             );
+            if pokemon_card.resistance.is_some() {
+                println!(
+                    "            resistance: {},",
+                    to_rust_energy(pokemon_card.resistance)
+                );
+            }
             println!(
                 "            retreat_cost: {},",
                 to_rust_energy_vec(&pokemon_card.retreat_cost)
@@ -142,6 +148,7 @@ fn print_card(enum_name: &str, card: &Card) {
                 "            booster_pack: \"{}\".to_string(),",
                 pokemon_card.booster_pack
             );
+            println!("            ..Default::default()");
             println!("        }}),");
         }
         Card::Trainer(trainer_card) => {
@@ -187,6 +194,16 @@ fn print_attacks(attacks: &[Attack]) {
             "                    effect: {},",
             to_rust_string(&attack.effect)
         );
+        if attack.first_turn_restricted {
+            println!("                    first_turn_restricted: true,");
+        }
+        if let Some(tool) = attack.required_tool {
+            println!("                    required_tool: Some(crate::tool_ids::ToolId::{tool:?}),");
+        }
+        if let Some(coin_flip) = attack.coin_flip {
+            println!("                    coin_flip: Some(crate::models::AttackCoinFlip::{coin_flip:?}),");
+        }
+        println!("                    ..Default::default()");
         println!("                }},");
     }
     println!("            ],");
@@ -1,9 +1,7 @@
 use clap::Parser;
-use deckgym::card_ids::CardId;
-use deckgym::card_validation::{get_implementation_status, ImplementationStatus};
+use deckgym::card_validation::{implemented_card_report, ImplementationStatus};
 use deckgym::database::get_card_by_enum;
 use std::collections::HashMap;
-use strum::IntoEnumIterator;
 
 #[derive(Parser)]
 #[command(name = "card_status")]
@@ -26,10 +24,10 @@ struct CardStatusInfo {
 
 // Filtering logic: collect all card statuses
 fn collect_card_statuses() -> Vec<CardStatusInfo> {
-    CardId::iter()
-        .map(|card_id| {
+    implemented_card_report()
+        .into_iter()
+        .map(|(card_id, status)| {
             let card = get_card_by_enum(card_id);
-            let status = get_implementation_status(card_id);
 
             CardStatusInfo {
                 id: card.get_id(),
@@ -0,0 +1,25 @@
+use crate::{card_ids::CardId, models::Card};
+
+/// Matches every printing of Type: Null.
+pub fn is_type_null(card: &Card) -> bool {
+    matches!(
+        card.get_card_id(),
+        CardId::A3a060TypeNull | CardId::A4b300TypeNull | CardId::A4b301TypeNull
+    )
+}
+
+/// Matches every printing of Silvally.
+pub fn is_silvally(card: &Card) -> bool {
+    matches!(
+        card.get_card_id(),
+        CardId::A3a061Silvally
+            | CardId::A3a074Silvally
+            | CardId::A4b302Silvally
+            | CardId::A4b303Silvally
+    )
+}
+
+/// Gladion searches the deck for a Type: Null or Silvally.
+pub fn is_gladion_target(card: &Card) -> bool {
+    is_type_null(card) || is_silvally(card)
+}
@@ -0,0 +1,14 @@
+use crate::{card_ids::CardId, models::PlayedCard};
+
+/// Mallow can only heal Shiinotic or Tsareena.
+pub fn is_mallow_target(pokemon: &PlayedCard) -> bool {
+    matches!(
+        pokemon.card.get_card_id(),
+        CardId::A1a008Shiinotic
+            | CardId::A3017Shiinotic
+            | CardId::A3a027Shiinotic
+            | CardId::A3020Tsareena
+            | CardId::A3158Tsareena
+            | CardId::A3b005Tsareena
+    )
+}
@@ -1,3 +1,7 @@
+mod gladion;
+mod mallow;
 mod rare_candy;
 
-pub use rare_candy::can_rare_candy_evolve;
+pub use gladion::{is_gladion_target, is_silvally, is_type_null};
+pub use mallow::is_mallow_target;
+pub use rare_candy::{can_rare_candy_evolve, is_rare_candy};
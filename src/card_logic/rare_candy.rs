@@ -7,6 +7,16 @@ use crate::{
     models::{Card, PlayedCard},
 };
 
+pub fn is_rare_candy(card: &Card) -> bool {
+    matches!(
+        card.get_card_id(),
+        CardId::A3144RareCandy
+            | CardId::A4b314RareCandy
+            | CardId::A4b315RareCandy
+            | CardId::A4b379RareCandy
+    )
+}
+
 /// Check if a Stage 2 Pokemon can evolve from a Basic Pokemon using Rare Candy
 pub fn can_rare_candy_evolve(stage2_card: &Card, basic_pokemon: &PlayedCard) -> bool {
     if let Card::Pokemon(stage2_pokemon) = stage2_card {
@@ -8,6 +8,7 @@ use crate::{
     AbilityId, AttackId,
 };
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ImplementationStatus {
@@ -15,7 +16,8 @@ pub enum ImplementationStatus {
     CardNotFound,
     MissingAttack,
     MissingAbility,
-    MissingTrainer,
+    MissingSupporter,
+    MissingItem,
     MissingTool,
 }
 
@@ -30,7 +32,8 @@ impl ImplementationStatus {
             ImplementationStatus::CardNotFound => "Card ID not found",
             ImplementationStatus::MissingAttack => "Attack effect not implemented",
             ImplementationStatus::MissingAbility => "Ability not implemented",
-            ImplementationStatus::MissingTrainer => "Trainer logic not implemented",
+            ImplementationStatus::MissingSupporter => "Supporter not implemented",
+            ImplementationStatus::MissingItem => "Item not implemented",
             ImplementationStatus::MissingTool => "Tool not implemented",
         }
     }
@@ -66,10 +69,63 @@ pub fn get_implementation_status(card_id: CardId) -> ImplementationStatus {
             // Verify it can generate moves
             let moves = trainer_move_generation_implementation(&State::default(), &trainer_card);
             if moves.is_none() {
-                return ImplementationStatus::MissingTrainer;
+                return match trainer_card.trainer_card_type {
+                    TrainerType::Supporter => ImplementationStatus::MissingSupporter,
+                    // Fossils are Item cards played as Basic Pokémon
+                    TrainerType::Item | TrainerType::Fossil => ImplementationStatus::MissingItem,
+                    TrainerType::Tool => ImplementationStatus::MissingTool,
+                };
             };
         }
     }
 
     ImplementationStatus::Complete
 }
+
+/// The implementation status of every card, in `CardId` declaration order, so that e.g. a
+/// frontend can render coverage in one call.
+pub fn implemented_card_report() -> Vec<(CardId, ImplementationStatus)> {
+    CardId::iter()
+        .map(|card_id| (card_id, get_implementation_status(card_id)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_trainers_report_their_trainer_type() {
+        assert_eq!(
+            get_implementation_status(CardId::A1224Brock),
+            ImplementationStatus::MissingSupporter
+        );
+        assert_eq!(
+            get_implementation_status(CardId::A1a064PokemonFlute),
+            ImplementationStatus::MissingItem
+        );
+        assert_eq!(
+            get_implementation_status(CardId::A1216HelixFossil),
+            ImplementationStatus::MissingItem
+        );
+        assert_eq!(
+            get_implementation_status(CardId::A2149LumBerry),
+            ImplementationStatus::MissingTool
+        );
+        assert_eq!(
+            get_implementation_status(CardId::A1219Erika),
+            ImplementationStatus::Complete
+        );
+    }
+
+    #[test]
+    fn test_implemented_card_report_covers_every_card_in_order() {
+        let report = implemented_card_report();
+        assert_eq!(report.len(), CardId::iter().count());
+        assert!(report
+            .iter()
+            .map(|(card_id, _)| *card_id)
+            .eq(CardId::iter()));
+        assert!(report.contains(&(CardId::A1001Bulbasaur, ImplementationStatus::Complete)));
+    }
+}
@@ -1,7 +1,8 @@
 use rand::seq::SliceRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::BTreeSet;
+use std::fmt;
 use std::fs;
 use std::hash::{Hash, Hasher};
 
@@ -11,6 +12,7 @@ use crate::models::{Card, EnergyType};
 
 /// Represents a deck of cards.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Deck {
     pub cards: Vec<Card>,
     pub(crate) energy_types: Vec<EnergyType>,
@@ -36,9 +38,16 @@ impl Deck {
     }
 
     pub fn from_string(contents: &str) -> Result<Self, String> {
-        let mut energy_types = HashSet::new();
+        Self::from_decklist(contents).map_err(|err| err.to_string())
+    }
+
+    /// Parses decklist text: one `<count> <set> <number>` line per card (e.g. `2 A1 001`),
+    /// plus optional `Energy: <type>` headers. If no energy is given, the deck uses the
+    /// energy types of its Pokémon.
+    pub fn from_decklist(text: &str) -> Result<Self, DeckParseError> {
+        let mut energy_types = BTreeSet::new();
         let mut cards = Vec::new();
-        for line in contents.lines() {
+        for line in text.lines() {
             // if line is empty or starts with "Pokemon:" or "Trainer:, skip it
             let trimmed = line.trim();
             if trimmed.is_empty()
@@ -50,18 +59,20 @@ impl Deck {
             if trimmed.starts_with("Energy:") {
                 let energy_type: &str = trimmed
                     .split_whitespace()
-                    .last()
-                    .expect("Energy: line should have an energy type");
-                energy_types
-                    .insert(EnergyType::from_str(energy_type).expect("Invalid energy type"));
+                    .nth(1)
+                    .ok_or_else(|| DeckParseError::InvalidLine(trimmed.to_string()))?;
+                let energy_type = EnergyType::from_str(energy_type)
+                    .ok_or_else(|| DeckParseError::UnknownEnergyType(energy_type.to_string()))?;
+                energy_types.insert(energy_type);
                 continue;
             }
 
-            let (count, card) = Card::from_str_with_count(trimmed)?;
+            let (count, card) = parse_card_line(trimmed)?;
             cards.extend(vec![card; count as usize]);
         }
 
-        // If empty energy types set, populate it with the energy types from the cards
+        // If empty energy types set, populate it with the energy types from the cards. Kept in a
+        //  BTreeSet so that the order (and thus seeded Energy Zone generation) is stable.
         if energy_types.is_empty() {
             cards.iter().for_each(|x| {
                 if let Card::Pokemon(pokemon_card) = x {
@@ -132,26 +143,51 @@ impl Deck {
 impl Card {
     /// Parses a line and returns a tuple of count and a `Card`.
     pub fn from_str_with_count(line: &str) -> Result<(u32, Card), String> {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 3 {
-            return Err(format!("Invalid card format: {line}"));
-        }
+        parse_card_line(line).map_err(|err| err.to_string())
+    }
+}
 
-        let count = parts[0]
-            .parse::<u32>()
-            .map_err(|_| format!("Invalid count: {}", parts[0]))?;
-        let set = parts[parts.len() - 2];
-        // maybe pad number with 0 on the left if missing 0s
-        let number = parts[parts.len() - 1];
-        let padded_number = format!("{number:0>3}");
-        let id = format!("{set} {padded_number}");
+/// Why a decklist couldn't be parsed (see `Deck::from_decklist`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckParseError {
+    InvalidLine(String),
+    InvalidCount(String),
+    UnknownCardId(String),
+    UnknownEnergyType(String),
+}
 
-        let card_id =
-            CardId::from_card_id(&id).ok_or_else(|| format!("Card ID not found for id: {id}"))?;
-        let card = get_card_by_enum(card_id);
+impl fmt::Display for DeckParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckParseError::InvalidLine(line) => write!(f, "Invalid card format: {line}"),
+            DeckParseError::InvalidCount(count) => write!(f, "Invalid count: {count}"),
+            DeckParseError::UnknownCardId(id) => write!(f, "Card ID not found for id: {id}"),
+            DeckParseError::UnknownEnergyType(energy) => write!(f, "Invalid energy type: {energy}"),
+        }
+    }
+}
+
+impl std::error::Error for DeckParseError {}
 
-        Ok((count, card.clone()))
+fn parse_card_line(line: &str) -> Result<(u32, Card), DeckParseError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 3 {
+        return Err(DeckParseError::InvalidLine(line.to_string()));
     }
+
+    let count = parts[0]
+        .parse::<u32>()
+        .map_err(|_| DeckParseError::InvalidCount(parts[0].to_string()))?;
+    let set = parts[parts.len() - 2];
+    // maybe pad number with 0 on the left if missing 0s
+    let number = parts[parts.len() - 1];
+    let padded_number = format!("{number:0>3}");
+    let id = format!("{set} {padded_number}");
+
+    let card_id = CardId::from_card_id(&id).ok_or(DeckParseError::UnknownCardId(id))?;
+    let card = get_card_by_enum(card_id);
+
+    Ok((count, card.clone()))
 }
 
 pub fn is_basic(card: &Card) -> bool {
@@ -274,4 +310,30 @@ Trainer: 12
         let deck = Deck::from_string(string).expect("Failed to parse deck from string");
         assert_eq!(deck.cards.len(), 20);
     }
+
+    #[test]
+    fn test_from_decklist() {
+        let decklist = "Energy: Water\n2 A1 053\n1 A1 223\n";
+        let deck = Deck::from_decklist(decklist).expect("Failed to parse decklist");
+        assert_eq!(deck.cards.len(), 3);
+        assert_eq!(deck.cards[0].get_name(), "Squirtle");
+        assert_eq!(deck.cards[2].get_name(), "Giovanni");
+        assert_eq!(deck.energy_types, vec![EnergyType::Water]);
+    }
+
+    #[test]
+    fn test_from_decklist_errors() {
+        assert_eq!(
+            Deck::from_decklist("2 A1 999"),
+            Err(DeckParseError::UnknownCardId("A1 999".to_string()))
+        );
+        assert_eq!(
+            Deck::from_decklist("two A1 001"),
+            Err(DeckParseError::InvalidCount("two".to_string()))
+        );
+        assert_eq!(
+            Deck::from_decklist("Energy: Plasma\n2 A1 001"),
+            Err(DeckParseError::UnknownEnergyType("Plasma".to_string()))
+        );
+    }
 }
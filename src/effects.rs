@@ -1,16 +1,28 @@
-use crate::attack_ids::AttackId;
+use crate::{attack_ids::AttackId, models::EnergyType};
 use serde::{Deserialize, Serialize};
 
 /// I believe these are the "clearable" ones by retreating...
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum CardEffect {
     NoRetreat,
-    ReducedDamage { amount: u32 },
+    ReducedDamage {
+        amount: u32,
+    },
     CannotAttack,
     CannotUseAttack(AttackId),
+    AttackDamageDebuff {
+        amount: u32,
+    },
+    TypedDamageReduction {
+        attacker_type: EnergyType,
+        amount: u32,
+    },
+    FreeRetreat,
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum TurnEffect {
     NoSupportCards,
     NoItemCards,
@@ -1,6 +1,6 @@
 use colored::Colorize;
 use log::{debug, info, trace};
-use rand::{rngs::StdRng, SeedableRng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use uuid::Uuid;
 
 use crate::{
@@ -8,11 +8,21 @@ use crate::{
     generate_possible_actions,
     models::EnergyType,
     players::Player,
+    replay::Replay,
     simulation_event_handler::{CompositeSimulationEventHandler, SimulationEventHandler},
     state::GameOutcome,
     State,
 };
 
+/// One action taken during a game, with the points standing right after it was applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnLog {
+    pub turn_count: u8,
+    pub actor: usize,
+    pub action: Action,
+    pub resulting_points: [u8; 2],
+}
+
 // It has a lifetime to allow it to borrow the event handler mutably for the duration of the game
 pub struct Game<'a> {
     seed: u64,
@@ -24,6 +34,10 @@ pub struct Game<'a> {
 
     debug: bool,
     event_handler: Option<&'a mut CompositeSimulationEventHandler>,
+    replay: Option<Replay>,
+    turn_log: Vec<TurnLog>,
+    // (turn_count, turn player) of the turn on_turn_start was last fired for
+    started_turn: Option<(u8, usize)>,
 }
 
 impl<'a> Game<'a> {
@@ -37,6 +51,9 @@ impl<'a> Game<'a> {
             state,
             debug: false,
             event_handler: None,
+            replay: None,
+            turn_log: Vec::new(),
+            started_turn: None,
         }
     }
 
@@ -53,6 +70,9 @@ impl<'a> Game<'a> {
             state,
             debug: true,
             event_handler: None,
+            replay: None,
+            turn_log: Vec::new(),
+            started_turn: None,
         }
     }
 
@@ -84,6 +104,15 @@ impl<'a> Game<'a> {
     }
 
     pub fn play_tick(&mut self) -> Action {
+        let turn_count = self.state.turn_count;
+        if self.started_turn.map(|(turn, _)| turn) != Some(turn_count) {
+            let turn_player = self.state.turn_player();
+            self.started_turn = Some((turn_count, turn_player));
+            if let Some(handler) = &mut self.event_handler {
+                handler.on_turn_start(self.id, turn_count, turn_player, &self.state);
+            }
+        }
+
         let (actor, actions) = generate_possible_actions(&self.state);
 
         let player = &self.players[actor];
@@ -111,16 +140,59 @@ impl<'a> Game<'a> {
         }
         self.apply_action(&action);
         self.print_state();
+        // Only snapshot settled states, not ones halfway through resolving an effect
+        if self.state.move_generation_stack.is_empty() || self.state.is_game_over() {
+            if let Some(handler) = &mut self.event_handler {
+                handler.on_state_snapshot(self.id, &self.state);
+            }
+        }
+        if self.state.turn_count != turn_count || self.state.is_game_over() {
+            if let (Some(handler), Some((turn, turn_player))) =
+                (&mut self.event_handler, self.started_turn)
+            {
+                handler.on_turn_end(self.id, turn, turn_player, &self.state);
+            }
+        }
+        self.turn_log.push(TurnLog {
+            turn_count,
+            actor,
+            action: action.clone(),
+            resulting_points: self.state.points,
+        });
         action
     }
 
+    /// Drains the actions played via `play_tick` since the last call.
+    pub fn take_turn_log(&mut self) -> Vec<TurnLog> {
+        std::mem::take(&mut self.turn_log)
+    }
+
     pub fn get_state_clone(&self) -> State {
         self.state.clone()
     }
 
     // TODO: Maybe make these only available for testing?
     pub fn apply_action(&mut self, action: &Action) {
-        apply_action(&mut self.rng, &mut self.state, action);
+        // Each action gets its own RNG, seeded from the game RNG, so that a Replay can re-apply
+        //  it without the player decisions that also draw from the game RNG.
+        let action_seed = self.rng.gen::<u64>();
+        if let Some(replay) = &mut self.replay {
+            replay.record(action, action_seed);
+        }
+        apply_action(
+            &mut StdRng::seed_from_u64(action_seed),
+            &mut self.state,
+            action,
+        );
+    }
+
+    /// Starts recording every applied action from the current state on (see Replay).
+    pub fn record_replay(&mut self) {
+        self.replay = Some(Replay::new(self.seed, self.state.clone()));
+    }
+
+    pub fn get_replay(&self) -> Option<&Replay> {
+        self.replay.as_ref()
     }
 
     pub fn set_state(&mut self, state: State) {
@@ -251,10 +323,4 @@ mod tests {
         game.play();
         assert_eq!(game.get_state_clone().turn_count, 5);
     }
-
-    // TODO: Look for a game that has bench, and pokemon can die from attack + poison
-    //   to launche the complicated sequence of Poison K.O. then user having
-    //   to select one pokemon to promote to active.
-
-    // TODO: Multiple bench KO
 }
@@ -4,8 +4,8 @@ use std::vec;
 use log::debug;
 
 use crate::{
-    actions::SimpleAction,
-    effects::{CardEffect, TurnEffect},
+    actions::{PendingChoice, SimpleAction},
+    effects::TurnEffect,
     models::{Card, EnergyType, PlayedCard, TrainerCard, TrainerType, BASIC_STAGE},
     tool_ids::ToolId,
     AbilityId, State,
@@ -95,31 +95,42 @@ pub(crate) fn can_evolve_into(evolution_card: &Card, base_pokemon: &PlayedCard)
 }
 
 pub(crate) fn on_attach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
+    // HP-boosting tools add to both remaining_hp and total_hp
+    let hp_bonus = get_tool_hp_bonus(tool_id);
+    if hp_bonus > 0 {
+        let card = state.in_play_pokemon[actor][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there if attaching a tool");
+        card.remaining_hp += hp_bonus;
+        card.total_hp += hp_bonus;
+    }
+}
+
+/// Called when a tool is removed from a Pokémon in play (e.g. by Guzma). Undoes any HP boost
+/// while keeping the damage taken, so the Pokémon may be left at 0 HP (i.e. K.O.ed).
+pub(crate) fn on_detach_tool(state: &mut State, actor: usize, in_play_idx: usize, tool_id: ToolId) {
+    let hp_bonus = get_tool_hp_bonus(tool_id);
+    if hp_bonus > 0 {
+        let card = state.in_play_pokemon[actor][in_play_idx]
+            .as_mut()
+            .expect("Pokemon should be there if detaching a tool");
+        card.remaining_hp = card.remaining_hp.saturating_sub(hp_bonus);
+        card.total_hp -= hp_bonus;
+    }
+}
+
+pub(crate) fn get_tool_hp_bonus(tool_id: ToolId) -> u32 {
     match tool_id {
-        ToolId::A2147GiantCape => {
-            // Add +20 to remaining_hp and total_hp
-            let card = state.in_play_pokemon[actor][in_play_idx]
-                .as_mut()
-                .expect("Active Pokemon should be there");
-            card.remaining_hp += 20;
-            card.total_hp += 20;
-        }
-        ToolId::A3147LeafCape => {
-            // Add +30 to remaining_hp and total_hp (only for Grass pokemon)
-            let card = state.in_play_pokemon[actor][in_play_idx]
-                .as_mut()
-                .expect("Active Pokemon should be there");
-            card.remaining_hp += 30;
-            card.total_hp += 30;
-        }
-        // Many tools do nothing on attach
+        ToolId::A2147GiantCape => 20,
+        ToolId::A3147LeafCape => 30, // only attachable to Grass pokemon
+        // Most tools don't change HP
         ToolId::A2148RockyHelmet
         | ToolId::A3146PoisonBarb
         | ToolId::A3a065ElectricalCord
         | ToolId::A4a067InflatableBoat
         | ToolId::A4b318ElectricalCord
         | ToolId::A4b319ElectricalCord
-        | ToolId::B1219HeavyHelmet => {}
+        | ToolId::B1219HeavyHelmet => 0,
     }
 }
 
@@ -143,7 +154,7 @@ pub(crate) fn on_attach_energy(
         {
             // Deal 20 damage to opponent's active Pokémon
             debug!("Darkrai ex's Nightmare Aura: Dealing 20 damage to opponent's active Pokemon");
-            let opponent = (actor + 1) % 2;
+            let opponent = State::opponent_of(actor);
             if let Some(opponent_active) = state.in_play_pokemon[opponent][0].as_mut() {
                 opponent_active.apply_damage(20);
             }
@@ -164,29 +175,31 @@ pub(crate) fn on_evolve(actor: usize, state: &mut State, to_card: &Card) {
     if let Some(ability_id) = AbilityId::from_pokemon_id(&to_card.get_id()[..]) {
         if ability_id == AbilityId::A3b034SylveonExHappyRibbon {
             // Give the user the option to draw 2 cards
-            state.move_generation_stack.push((
+            state.push_pending_choice(
                 actor,
-                vec![SimpleAction::DrawCard { amount: 2 }, SimpleAction::Noop],
-            ));
+                PendingChoice::SelectTarget(vec![
+                    SimpleAction::DrawCard { amount: 2 },
+                    SimpleAction::Noop,
+                ]),
+            );
         }
         if ability_id == AbilityId::A4a022MiloticHealingRipples {
             // Healing Ripples: heal 60 damage from 1 of your [W] Pokémon
-            let possible_moves: Vec<SimpleAction> = state
-                .enumerate_in_play_pokemon(actor)
-                .filter(|(_, pokemon)| {
-                    pokemon.is_damaged() && pokemon.get_energy_type() == Some(EnergyType::Water)
-                })
+            let mut possible_moves: Vec<SimpleAction> = state
+                .enumerate_damaged_pokemon(actor)
+                .filter(|(_, pokemon)| pokemon.get_energy_type() == Some(EnergyType::Water))
                 .map(|(in_play_idx, _)| SimpleAction::Heal {
                     in_play_idx,
                     amount: 60,
                     cure_status: false,
                 })
-                .chain(std::iter::once(SimpleAction::Noop))
                 .collect();
 
-            if possible_moves.len() > 1 {
-                state.move_generation_stack.push((actor, possible_moves));
+            // Only offer to skip the heal if there is something to heal
+            if !possible_moves.is_empty() {
+                possible_moves.push(SimpleAction::Noop);
             }
+            state.push_pending_choice(actor, PendingChoice::SelectTarget(possible_moves));
         }
     }
 }
@@ -201,10 +214,10 @@ pub(crate) fn on_end_turn(player_ending_turn: usize, state: &mut State) {
         {
             // At the end of your turn, if this Pokémon is in the Active Spot, draw a card.
             debug!("Legendary Pulse: Drawing a card");
-            state.move_generation_stack.push((
+            state.push_pending_choice(
                 player_ending_turn,
-                vec![SimpleAction::DrawCard { amount: 1 }],
-            ));
+                PendingChoice::SelectTarget(vec![SimpleAction::DrawCard { amount: 1 }]),
+            );
         }
         if ability_id == AbilityId::A3b057SnorlaxExFullMouthManner {
             // At the end of your turn, if this Pokémon is in the Active Spot, heal 20 damage from it.
@@ -249,7 +262,7 @@ pub(crate) fn can_play_support(state: &State) -> bool {
         .any(|x| matches!(x, TurnEffect::NoSupportCards));
 
     // Check if opponent has Gengar ex with Shadowy Spellbind in active spot
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let blocked_by_gengar = state.in_play_pokemon[opponent][0]
         .as_ref()
         .and_then(|opponent_active| AbilityId::from_pokemon_id(&opponent_active.get_id()))
@@ -268,157 +281,6 @@ pub(crate) fn can_play_item(state: &State) -> bool {
     !has_modifiers
 }
 
-fn get_heavy_helmet_reduction(state: &State, (target_player, target_idx): (usize, usize)) -> u32 {
-    let defending_pokemon = &state.in_play_pokemon[target_player][target_idx]
-        .as_ref()
-        .expect("Defending Pokemon should be there when checking Heavy Helmet");
-    if let Some(tool_id) = defending_pokemon.attached_tool {
-        if tool_id == ToolId::B1219HeavyHelmet {
-            if let Card::Pokemon(pokemon_card) = &defending_pokemon.card {
-                if pokemon_card.retreat_cost.len() >= 3 {
-                    debug!("Heavy Helmet: Reducing damage by 20");
-                    return 20;
-                }
-            }
-        }
-    }
-    0
-}
-
-fn get_intimidating_fang_reduction(
-    state: &State,
-    attacking_ref: (usize, usize),
-    target_ref: (u32, usize, usize),
-    is_from_active_attack: bool,
-) -> u32 {
-    let (attacking_player, attacking_idx) = attacking_ref;
-    let (_, target_player, _) = target_ref;
-    if attacking_player == target_player || attacking_idx != 0 || !is_from_active_attack {
-        return 0;
-    }
-
-    let defenders_active = &state.in_play_pokemon[target_player][0]
-        .as_ref()
-        .expect("Defending Pokemon should be there when checking Intimidating Fang");
-    if let Some(ability_id) = AbilityId::from_pokemon_id(&defenders_active.card.get_id()[..]) {
-        if ability_id == AbilityId::A3a015LuxrayIntimidatingFang {
-            debug!("Intimidating Fang: Reducing opponent's attack damage by 20");
-            return 20;
-        }
-    }
-    0
-}
-
-// TODO: Confirm is_from_attack and goes to enemy active
-pub(crate) fn modify_damage(
-    state: &State,
-    attacking_ref: (usize, usize),
-    target_ref: (u32, usize, usize),
-    is_from_active_attack: bool,
-) -> u32 {
-    // If attack is 0, not even Giovanni takes it to 10.
-    let (attacking_player, attacking_idx) = attacking_ref;
-    let (base_damage, target_player, target_idx) = target_ref;
-    if base_damage == 0 {
-        debug!("Attack is 0, returning 0");
-        return base_damage;
-    }
-
-    let attacking_pokemon = state.in_play_pokemon[attacking_player][attacking_idx]
-        .as_ref()
-        .expect("Attacking Pokemon should be there when modifying damage");
-    let receiving_pokemon = state.in_play_pokemon[target_player][target_idx]
-        .as_ref()
-        .expect("Receiving Pokemon should be there when modifying damage");
-
-    // Check for Safeguard ability (prevents all damage from opponent's Pokémon ex)
-    if let Some(ability_id) = AbilityId::from_pokemon_id(&receiving_pokemon.card.get_id()[..]) {
-        if ability_id == AbilityId::A3066OricoricSafeguard
-            && is_from_active_attack
-            && attacking_pokemon.card.is_ex()
-        {
-            debug!("Safeguard: Preventing all damage from opponent's Pokémon ex");
-            return 0;
-        }
-    }
-
-    // Intimidating Fang ability damage reduction
-    let intimidating_fang_reduction =
-        get_intimidating_fang_reduction(state, attacking_ref, target_ref, is_from_active_attack);
-    // Heavy Helmet damage reduction
-    let heavy_helmet_reduction = get_heavy_helmet_reduction(state, (target_player, target_idx));
-
-    // Modifiers by effect (like Giovanni, Red, Eevee Bag), most apply just to active-to-active attacks
-    let is_active_to_active = target_idx == 0 && attacking_idx == 0 && is_from_active_attack;
-    let target_is_ex = receiving_pokemon.card.is_ex();
-    let attacker_is_eevee_evolution = attacking_pokemon.evolved_from("Eevee");
-    let increased_turn_effect_modifiers = if !is_active_to_active {
-        0
-    } else {
-        state
-            .get_current_turn_effects()
-            .iter()
-            .map(|effect| match effect {
-                TurnEffect::IncreasedDamage { amount } => *amount,
-                TurnEffect::IncreasedDamageAgainstEx { amount } if target_is_ex => *amount,
-                TurnEffect::IncreasedDamageForEeveeEvolutions { amount }
-                    if attacker_is_eevee_evolution =>
-                {
-                    *amount
-                }
-                _ => 0,
-            })
-            .sum::<u32>()
-    };
-
-    // Modifiers by receiving card effects
-    let reduced_card_effect_modifiers = if !is_active_to_active {
-        0
-    } else {
-        state
-            .get_active(target_player)
-            .get_active_effects()
-            .iter()
-            .filter(|effect| matches!(effect, CardEffect::ReducedDamage { .. }))
-            .map(|effect| match effect {
-                CardEffect::ReducedDamage { amount } => *amount,
-                _ => 0,
-            })
-            .sum::<u32>()
-    };
-
-    // Weakness Modifier
-    let weakness_modifier = if !is_active_to_active {
-        0
-    } else {
-        let receiving = state.get_active(target_player);
-        if let Card::Pokemon(pokemon_card) = &receiving.card {
-            if pokemon_card.weakness == attacking_pokemon.card.get_type() {
-                debug!(
-                    "Weakness! {:?} is weak to {:?}",
-                    pokemon_card,
-                    attacking_pokemon.card.get_type()
-                );
-                return 20;
-            }
-        }
-        0
-    };
-
-    debug!(
-        "Attack: {:?}, Weakness: {}, IncreasedDamage: {}, ReducedDamage: {}, HeavyHelmet: {}, IntimidatingFang: {}",
-        base_damage,
-        weakness_modifier,
-        increased_turn_effect_modifiers,
-        reduced_card_effect_modifiers,
-        heavy_helmet_reduction,
-        intimidating_fang_reduction
-    );
-    (base_damage + weakness_modifier + increased_turn_effect_modifiers).saturating_sub(
-        reduced_card_effect_modifiers + heavy_helmet_reduction + intimidating_fang_reduction,
-    )
-}
-
 // Get the attack cost, considering opponent's abilities that modify attack costs (like Goomy's Sticky Membrane)
 pub(crate) fn get_attack_cost(
     base_cost: &[EnergyType],
@@ -429,7 +291,7 @@ pub(crate) fn get_attack_cost(
     let mut modified_cost = base_cost.to_vec();
 
     // Check if opponent has Goomy with Sticky Membrane in the active spot
-    let opponent = (attacking_player + 1) % 2;
+    let opponent = State::opponent_of(attacking_player);
     if let Some(opponent_active) = &state.in_play_pokemon[opponent][0] {
         if let Some(ability_id) = AbilityId::from_pokemon_id(&opponent_active.get_id()[..]) {
             if ability_id == AbilityId::B1177GoomyStickyMembrane {
@@ -449,6 +311,9 @@ pub(crate) fn contains_energy(
     state: &State,
     player: usize,
 ) -> bool {
+    if cost.iter().all(|x| *x == EnergyType::Colorless) {
+        return pokemon.effective_energy_count(state, player) >= cost.len();
+    }
     energy_missing(pokemon, cost, state, player).is_empty()
 }
 
@@ -494,12 +359,14 @@ pub(crate) fn on_knockout(
         .expect("Pokemon should be there if knocked out");
 
     // Handle Electrical Cord
-    if matches!(
-        knocked_out_pokemon.attached_tool,
-        Some(ToolId::A3a065ElectricalCord)
-            | Some(ToolId::A4b318ElectricalCord)
-            | Some(ToolId::A4b319ElectricalCord)
-    ) {
+    if knocked_out_pokemon.attached_tools.iter().any(|tool| {
+        matches!(
+            tool,
+            ToolId::A3a065ElectricalCord
+                | ToolId::A4b318ElectricalCord
+                | ToolId::A4b319ElectricalCord
+        )
+    }) {
         // Only triggers if knocked out in active spot from an active attack
         if knocked_out_idx != 0 || !is_from_active_attack {
             return;
@@ -597,6 +464,60 @@ mod tests {
         assert!(contains_energy(&pokemon, &cost, &state, 0));
     }
 
+    #[test]
+    fn test_contains_energy_two_colorless_paid_by_grass() {
+        let state = State::default();
+        let grass_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut pokemon = to_playable_card(&grass_card, false);
+        pokemon.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let cost = vec![EnergyType::Colorless, EnergyType::Colorless];
+        assert!(contains_energy(&pokemon, &cost, &state, 0));
+    }
+
+    #[test]
+    fn test_contains_energy_mixed_cost_uses_surplus_for_colorless() {
+        let state = State::default();
+        let grass_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut pokemon = to_playable_card(&grass_card, false);
+        pokemon.attached_energy = vec![EnergyType::Water, EnergyType::Grass, EnergyType::Fire];
+        let cost = vec![
+            EnergyType::Colorless,
+            EnergyType::Grass,
+            EnergyType::Colorless,
+        ];
+        assert!(contains_energy(&pokemon, &cost, &state, 0));
+    }
+
+    #[test]
+    fn test_contains_energy_underfunded_colorless() {
+        let state = State::default();
+        let grass_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut pokemon = to_playable_card(&grass_card, false);
+        pokemon.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let cost = vec![
+            EnergyType::Grass,
+            EnergyType::Colorless,
+            EnergyType::Colorless,
+        ];
+        assert!(!contains_energy(&pokemon, &cost, &state, 0));
+        assert_eq!(
+            energy_missing(&pokemon, &cost, &state, 0),
+            vec![EnergyType::Colorless]
+        );
+    }
+
+    #[test]
+    fn test_contains_energy_colorless_with_jungle_totem() {
+        let mut state = State::default();
+        let serperior_card = get_card_by_enum(CardId::A1a006Serperior);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&serperior_card, false));
+        let grass_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut pokemon = to_playable_card(&grass_card, false);
+        pokemon.attached_energy = vec![EnergyType::Grass];
+        let cost = vec![EnergyType::Grass, EnergyType::Colorless];
+        assert!(contains_energy(&pokemon, &cost, &state, 0));
+    }
+
     #[test]
     fn test_baby_pokemon_contain_energy() {
         let state = State::default();
@@ -626,96 +547,6 @@ mod tests {
         assert!(!can_play_support(&state));
     }
 
-    #[test]
-    fn test_giovanni_modifier() {
-        // Create a basic state with attacking and defending Pokémon
-        let mut state = State::default();
-
-        // Set up attacker with a fixed damage attack
-        let attacker = get_card_by_enum(CardId::A1001Bulbasaur);
-        let played_attacker = to_playable_card(&attacker, false);
-        state.in_play_pokemon[0][0] = Some(played_attacker);
-
-        // Set up defender
-        let defender = get_card_by_enum(CardId::A1033Charmander);
-        let played_defender = to_playable_card(&defender, false);
-        state.in_play_pokemon[1][0] = Some(played_defender);
-
-        // Get base damage without Giovanni effect
-        let attack = attacker.get_attacks()[0].clone();
-        let base_damage = modify_damage(&state, (0, 0), (attack.fixed_damage, 1, 0), true);
-
-        // Add Giovanni effect
-        state.add_turn_effect(TurnEffect::IncreasedDamage { amount: 10 }, 0);
-
-        // Get damage with Giovanni effect
-        let damage_with_giovanni = modify_damage(&state, (0, 0), (attack.fixed_damage, 1, 0), true);
-
-        // Verify Giovanni adds exactly 10 damage
-        assert_eq!(
-            damage_with_giovanni,
-            base_damage + 10,
-            "Giovanni should add exactly 10 damage to attacks"
-        );
-    }
-
-    #[test]
-    fn test_red_modifier_only_affects_ex() {
-        let attacker_card = get_card_by_enum(CardId::A1001Bulbasaur);
-
-        // Non-EX opponent should not receive extra damage
-        let mut non_ex_state = State::default();
-        non_ex_state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker_card, false));
-        let non_ex_defender = get_card_by_enum(CardId::A1033Charmander);
-        non_ex_state.in_play_pokemon[1][0] = Some(to_playable_card(&non_ex_defender, false));
-        let base_damage_non_ex = modify_damage(&non_ex_state, (0, 0), (40, 1, 0), true);
-        non_ex_state.add_turn_effect(TurnEffect::IncreasedDamageAgainstEx { amount: 20 }, 0);
-        let damage_with_red_vs_non_ex = modify_damage(&non_ex_state, (0, 0), (40, 1, 0), true);
-        assert_eq!(
-            damage_with_red_vs_non_ex, base_damage_non_ex,
-            "Red should not increase damage against non-EX Pokémon"
-        );
-
-        // EX opponent should receive the bonus damage
-        let mut ex_state = State::default();
-        ex_state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker_card, false));
-        let ex_defender = get_card_by_enum(CardId::A3122SolgaleoEx);
-        ex_state.in_play_pokemon[1][0] = Some(to_playable_card(&ex_defender, false));
-        let base_damage_ex = modify_damage(&ex_state, (0, 0), (40, 1, 0), true);
-        ex_state.add_turn_effect(TurnEffect::IncreasedDamageAgainstEx { amount: 20 }, 0);
-        let damage_with_red_vs_ex = modify_damage(&ex_state, (0, 0), (40, 1, 0), true);
-        assert_eq!(
-            damage_with_red_vs_ex,
-            base_damage_ex + 20,
-            "Red should add 20 damage against Pokémon ex"
-        );
-    }
-
-    #[test]
-    fn test_cosmoem_reduced_damage() {
-        // Arrange
-        let mut state = State::default();
-        let attacker = get_card_by_enum(CardId::A3122SolgaleoEx);
-        let played_attacker = to_playable_card(&attacker, false);
-        state.in_play_pokemon[0][0] = Some(played_attacker);
-        let defender = get_card_by_enum(CardId::A3086Cosmoem);
-        let played_defender = to_playable_card(&defender, false);
-        state.in_play_pokemon[1][0] = Some(played_defender);
-        state.in_play_pokemon[1][0]
-            .as_mut()
-            .unwrap()
-            .add_effect(crate::effects::CardEffect::ReducedDamage { amount: 50 }, 1);
-
-        // Act
-        let damage_with_stiffen = modify_damage(&state, (0, 0), (120, 1, 0), true);
-
-        // Assert
-        assert_eq!(
-            damage_with_stiffen, 70,
-            "Cosmoem's Stiffen should reduce damage by exactly 50"
-        );
-    }
-
     #[test]
     fn test_normal_evolution_works() {
         // Ivysaur evolves from Bulbasaur
@@ -3,8 +3,8 @@ use crate::{card_ids::CardId, models::PlayedCard, tool_ids::ToolId};
 /// Some cards counterattack either because of RockyHelmet or because of their own ability.
 pub(crate) fn get_counterattack_damage(card: &PlayedCard) -> u32 {
     let mut total_damage = 0;
-    if let Some(tool) = card.attached_tool {
-        if tool == ToolId::A2148RockyHelmet {
+    for tool in &card.attached_tools {
+        if *tool == ToolId::A2148RockyHelmet {
             total_damage += 20;
         }
     }
@@ -24,10 +24,5 @@ pub(crate) fn get_counterattack_damage(card: &PlayedCard) -> u32 {
 /// Check if the defending Pokemon should poison the attacker when damaged.
 /// Returns true if the attacker should be poisoned.
 pub(crate) fn should_poison_attacker(card: &PlayedCard) -> bool {
-    if let Some(tool) = card.attached_tool {
-        if tool == ToolId::A3146PoisonBarb {
-            return true;
-        }
-    }
-    false
+    card.has_tool(ToolId::A3146PoisonBarb)
 }
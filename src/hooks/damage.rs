@@ -0,0 +1,494 @@
+use log::debug;
+
+use crate::{
+    effects::{CardEffect, TurnEffect},
+    models::{Card, EnergyType, PlayedCard},
+    tool_ids::ToolId,
+    AbilityId, State,
+};
+
+/// Everything the damage modifiers look at when a Pokémon deals `base` damage to another.
+pub(crate) struct DamageContext<'a> {
+    pub state: &'a State,
+    pub base: u32,
+    pub player: usize,
+    pub attacker_idx: usize,
+    pub attacker: &'a PlayedCard,
+    pub target_player: usize,
+    pub target_idx: usize,
+    pub defender: &'a PlayedCard,
+    pub is_from_active_attack: bool,
+}
+
+impl<'a> DamageContext<'a> {
+    pub(crate) fn new(
+        state: &'a State,
+        attacking_ref: (usize, usize),
+        target_ref: (u32, usize, usize),
+        is_from_active_attack: bool,
+    ) -> Self {
+        let (player, attacker_idx) = attacking_ref;
+        let (base, target_player, target_idx) = target_ref;
+        DamageContext {
+            state,
+            base,
+            player,
+            attacker_idx,
+            attacker: state.in_play_pokemon[player][attacker_idx]
+                .as_ref()
+                .expect("Attacking Pokemon should be there when modifying damage"),
+            target_player,
+            target_idx,
+            defender: state.in_play_pokemon[target_player][target_idx]
+                .as_ref()
+                .expect("Receiving Pokemon should be there when modifying damage"),
+            is_from_active_attack,
+        }
+    }
+
+    /// Most modifiers (weakness, Giovanni, Red...) only apply to active-to-active attacks.
+    fn is_active_to_active(&self) -> bool {
+        self.target_idx == 0 && self.attacker_idx == 0 && self.is_from_active_attack
+    }
+}
+
+/// Applies damage modifiers in this order:
+/// 1. base damage (0 stays 0, not even Giovanni raises it; Safeguard prevents it all)
+/// 2. add weakness
+/// 3. add flat boosts (Giovanni, Red, Eevee Bag)
+/// 4. subtract resistance, flooring at 0
+/// 5. subtract reductions (defender effects, attacker debuffs, Heavy Helmet, Intimidating
+///    Fang), flooring at 0
+// TODO: Confirm is_from_attack and goes to enemy active
+pub(crate) fn compute_final_damage(ctx: &DamageContext) -> u32 {
+    if ctx.base == 0 {
+        debug!("Attack is 0, returning 0");
+        return 0;
+    }
+    if is_prevented_by_safeguard(ctx) {
+        debug!("Safeguard: Preventing all damage from opponent's Pokémon ex");
+        return 0;
+    }
+
+    let weakness = weakness_bonus(ctx);
+    let boosts = flat_boosts(ctx);
+    let damage = ctx.base + weakness + boosts;
+    let resistance = damage - resisted_damage(ctx, damage);
+    let reductions = defender_effect_reductions(ctx)
+        + attack_debuffs(ctx)
+        + get_heavy_helmet_reduction(ctx)
+        + get_intimidating_fang_reduction(ctx);
+
+    debug!(
+        "Attack: {:?}, Weakness: {}, Boosts: {}, Resistance: {}, Reductions: {}",
+        ctx.base, weakness, boosts, resistance, reductions
+    );
+    (damage - resistance).saturating_sub(reductions)
+}
+
+/// Safeguard prevents all damage from the opponent's Pokémon ex attacks.
+fn is_prevented_by_safeguard(ctx: &DamageContext) -> bool {
+    ctx.is_from_active_attack
+        && ctx.attacker.card.is_ex()
+        && AbilityId::from_pokemon_id(&ctx.defender.card.get_id()[..])
+            == Some(AbilityId::A3066OricoricSafeguard)
+}
+
+fn weakness_bonus(ctx: &DamageContext) -> u32 {
+    match ctx.attacker.card.get_type() {
+        Some(attacker_type) if ctx.is_active_to_active() => {
+            apply_weakness(attacker_type, &ctx.defender.card, ctx.base) - ctx.base
+        }
+        _ => 0,
+    }
+}
+
+/// Turn effects that raise damage (Giovanni, Red, Eevee Bag).
+fn flat_boosts(ctx: &DamageContext) -> u32 {
+    if !ctx.is_active_to_active() {
+        return 0;
+    }
+    let target_is_ex = ctx.defender.card.is_ex();
+    let attacker_is_eevee_evolution = ctx.attacker.evolved_from("Eevee");
+    ctx.state
+        .get_current_turn_effects()
+        .iter()
+        .map(|effect| match effect {
+            TurnEffect::IncreasedDamage { amount } => *amount,
+            TurnEffect::IncreasedDamageAgainstEx { amount } if target_is_ex => *amount,
+            TurnEffect::IncreasedDamageForEeveeEvolutions { amount }
+                if attacker_is_eevee_evolution =>
+            {
+                *amount
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn resisted_damage(ctx: &DamageContext, damage: u32) -> u32 {
+    match ctx.attacker.card.get_type() {
+        Some(attacker_type) if ctx.is_active_to_active() => {
+            apply_resistance(attacker_type, &ctx.defender.card, damage)
+        }
+        _ => damage,
+    }
+}
+
+/// Effects on the defending Active that reduce damage taken (like Cosmoem's Stiffen).
+fn defender_effect_reductions(ctx: &DamageContext) -> u32 {
+    if !ctx.is_active_to_active() {
+        return 0;
+    }
+    let attacker_type = ctx.attacker.card.get_type();
+    ctx.state
+        .get_active(ctx.target_player)
+        .iter_active_effects()
+        .map(|effect| match effect {
+            CardEffect::ReducedDamage { amount } => amount,
+            CardEffect::TypedDamageReduction {
+                attacker_type: reduced_type,
+                amount,
+            } if attacker_type == Some(reduced_type) => amount,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Effects on the attacker that lower its attacks' damage (like Cubone's Growl).
+fn attack_debuffs(ctx: &DamageContext) -> u32 {
+    if !(ctx.attacker_idx == 0 && ctx.is_from_active_attack) {
+        return 0;
+    }
+    ctx.attacker
+        .iter_active_effects()
+        .map(|effect| match effect {
+            CardEffect::AttackDamageDebuff { amount } => amount,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn get_heavy_helmet_reduction(ctx: &DamageContext) -> u32 {
+    if ctx.defender.has_tool(ToolId::B1219HeavyHelmet) {
+        if let Card::Pokemon(pokemon_card) = &ctx.defender.card {
+            if pokemon_card.retreat_cost.len() >= 3 {
+                debug!("Heavy Helmet: Reducing damage by 20");
+                return 20;
+            }
+        }
+    }
+    0
+}
+
+fn get_intimidating_fang_reduction(ctx: &DamageContext) -> u32 {
+    if ctx.player == ctx.target_player || ctx.attacker_idx != 0 || !ctx.is_from_active_attack {
+        return 0;
+    }
+
+    let defenders_active = ctx.state.get_active(ctx.target_player);
+    if AbilityId::from_pokemon_id(&defenders_active.card.get_id()[..])
+        == Some(AbilityId::A3a015LuxrayIntimidatingFang)
+    {
+        debug!("Intimidating Fang: Reducing opponent's attack damage by 20");
+        return 20;
+    }
+    0
+}
+
+/// Adds the +20 weakness bonus if the defender is weak to the attacker's type.
+/// Colorless attackers never hit a weakness.
+pub(crate) fn apply_weakness(
+    attacker_type: EnergyType,
+    defender_card: &Card,
+    base_damage: u32,
+) -> u32 {
+    if let Card::Pokemon(pokemon_card) = defender_card {
+        if attacker_type != EnergyType::Colorless && pokemon_card.weakness == Some(attacker_type) {
+            debug!(
+                "Weakness! {} is weak to {:?}",
+                pokemon_card.name, attacker_type
+            );
+            return base_damage + 20;
+        }
+    }
+    base_damage
+}
+
+/// Subtracts the -20 resistance if the defender resists the attacker's type (flooring at 0).
+/// Colorless attackers never hit a resistance.
+pub(crate) fn apply_resistance(
+    attacker_type: EnergyType,
+    defender_card: &Card,
+    damage: u32,
+) -> u32 {
+    if let Card::Pokemon(pokemon_card) = defender_card {
+        if attacker_type != EnergyType::Colorless && pokemon_card.resistance == Some(attacker_type)
+        {
+            debug!(
+                "Resistance! {} resists {:?}",
+                pokemon_card.name, attacker_type
+            );
+            return damage.saturating_sub(20);
+        }
+    }
+    damage
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card};
+
+    use super::*;
+
+    fn final_damage(
+        state: &State,
+        attacking_ref: (usize, usize),
+        target_ref: (u32, usize, usize),
+        is_from_active_attack: bool,
+    ) -> u32 {
+        compute_final_damage(&DamageContext::new(
+            state,
+            attacking_ref,
+            target_ref,
+            is_from_active_attack,
+        ))
+    }
+
+    #[test]
+    fn test_giovanni_modifier() {
+        // Create a basic state with attacking and defending Pokémon
+        let mut state = State::default();
+
+        // Set up attacker with a fixed damage attack
+        let attacker = get_card_by_enum(CardId::A1001Bulbasaur);
+        let played_attacker = to_playable_card(&attacker, false);
+        state.in_play_pokemon[0][0] = Some(played_attacker);
+
+        // Set up defender
+        let defender = get_card_by_enum(CardId::A1033Charmander);
+        let played_defender = to_playable_card(&defender, false);
+        state.in_play_pokemon[1][0] = Some(played_defender);
+
+        // Get base damage without Giovanni effect
+        let attack = attacker.get_attacks()[0].clone();
+        let base_damage = final_damage(&state, (0, 0), (attack.fixed_damage, 1, 0), true);
+
+        // Add Giovanni effect
+        state.add_turn_effect(TurnEffect::IncreasedDamage { amount: 10 }, 0);
+
+        // Get damage with Giovanni effect
+        let damage_with_giovanni = final_damage(&state, (0, 0), (attack.fixed_damage, 1, 0), true);
+
+        // Verify Giovanni adds exactly 10 damage
+        assert_eq!(
+            damage_with_giovanni,
+            base_damage + 10,
+            "Giovanni should add exactly 10 damage to attacks"
+        );
+    }
+
+    #[test]
+    fn test_red_modifier_only_affects_ex() {
+        let attacker_card = get_card_by_enum(CardId::A1001Bulbasaur);
+
+        // Non-EX opponent should not receive extra damage
+        let mut non_ex_state = State::default();
+        non_ex_state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker_card, false));
+        let non_ex_defender = get_card_by_enum(CardId::A1033Charmander);
+        non_ex_state.in_play_pokemon[1][0] = Some(to_playable_card(&non_ex_defender, false));
+        let base_damage_non_ex = final_damage(&non_ex_state, (0, 0), (40, 1, 0), true);
+        non_ex_state.add_turn_effect(TurnEffect::IncreasedDamageAgainstEx { amount: 20 }, 0);
+        let damage_with_red_vs_non_ex = final_damage(&non_ex_state, (0, 0), (40, 1, 0), true);
+        assert_eq!(
+            damage_with_red_vs_non_ex, base_damage_non_ex,
+            "Red should not increase damage against non-EX Pokémon"
+        );
+
+        // EX opponent should receive the bonus damage
+        let mut ex_state = State::default();
+        ex_state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker_card, false));
+        let ex_defender = get_card_by_enum(CardId::A3122SolgaleoEx);
+        ex_state.in_play_pokemon[1][0] = Some(to_playable_card(&ex_defender, false));
+        let base_damage_ex = final_damage(&ex_state, (0, 0), (40, 1, 0), true);
+        ex_state.add_turn_effect(TurnEffect::IncreasedDamageAgainstEx { amount: 20 }, 0);
+        let damage_with_red_vs_ex = final_damage(&ex_state, (0, 0), (40, 1, 0), true);
+        assert_eq!(
+            damage_with_red_vs_ex,
+            base_damage_ex + 20,
+            "Red should add 20 damage against Pokémon ex"
+        );
+    }
+
+    #[test]
+    fn test_apply_weakness_match() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur); // Weak to Fire
+        assert_eq!(apply_weakness(EnergyType::Fire, &bulbasaur, 40), 60);
+    }
+
+    #[test]
+    fn test_apply_weakness_no_match() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        assert_eq!(apply_weakness(EnergyType::Water, &bulbasaur, 40), 40);
+    }
+
+    #[test]
+    fn test_apply_weakness_colorless_never_matches() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        assert_eq!(apply_weakness(EnergyType::Colorless, &bulbasaur, 40), 40);
+    }
+
+    /// Bulbasaur with a Water resistance (no printed Pocket card has one).
+    fn water_resistant_bulbasaur() -> Card {
+        let mut bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        if let Card::Pokemon(pokemon_card) = &mut bulbasaur {
+            pokemon_card.resistance = Some(EnergyType::Water);
+        }
+        bulbasaur
+    }
+
+    #[test]
+    fn test_apply_resistance_match() {
+        let bulbasaur = water_resistant_bulbasaur();
+        assert_eq!(apply_resistance(EnergyType::Water, &bulbasaur, 40), 20);
+        assert_eq!(apply_resistance(EnergyType::Water, &bulbasaur, 10), 0);
+    }
+
+    #[test]
+    fn test_apply_resistance_no_match() {
+        let bulbasaur = water_resistant_bulbasaur();
+        assert_eq!(apply_resistance(EnergyType::Fire, &bulbasaur, 40), 40);
+        assert_eq!(apply_resistance(EnergyType::Colorless, &bulbasaur, 40), 40);
+    }
+
+    #[test]
+    fn test_resistance_is_applied_in_final_damage() {
+        let mut state = State::default();
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&squirtle, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&water_resistant_bulbasaur(), false));
+        assert_eq!(final_damage(&state, (0, 0), (30, 1, 0), true), 10);
+        // Reductions are applied after resistance, still flooring at 0
+        state.in_play_pokemon[1][0]
+            .as_mut()
+            .unwrap()
+            .add_effect(CardEffect::ReducedDamage { amount: 20 }, 1);
+        assert_eq!(final_damage(&state, (0, 0), (30, 1, 0), true), 0);
+    }
+
+    #[test]
+    fn test_weakness_is_added_in_final_damage() {
+        let mut state = State::default();
+        let charmander = get_card_by_enum(CardId::A1033Charmander);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&charmander, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasaur, false));
+        assert_eq!(final_damage(&state, (0, 0), (30, 1, 0), true), 50);
+        // Bench damage doesn't apply weakness
+        state.in_play_pokemon[1][1] = Some(to_playable_card(&bulbasaur, false));
+        assert_eq!(final_damage(&state, (0, 0), (30, 1, 1), true), 30);
+    }
+
+    #[test]
+    fn test_cosmoem_reduced_damage() {
+        // Arrange
+        let mut state = State::default();
+        let attacker = get_card_by_enum(CardId::A3122SolgaleoEx);
+        let played_attacker = to_playable_card(&attacker, false);
+        state.in_play_pokemon[0][0] = Some(played_attacker);
+        let defender = get_card_by_enum(CardId::A3086Cosmoem);
+        let played_defender = to_playable_card(&defender, false);
+        state.in_play_pokemon[1][0] = Some(played_defender);
+        state.in_play_pokemon[1][0]
+            .as_mut()
+            .unwrap()
+            .add_effect(crate::effects::CardEffect::ReducedDamage { amount: 50 }, 1);
+
+        // Act
+        let damage_with_stiffen = final_damage(&state, (0, 0), (120, 1, 0), true);
+
+        // Assert
+        assert_eq!(
+            damage_with_stiffen, 70,
+            "Cosmoem's Stiffen should reduce damage by exactly 50"
+        );
+    }
+
+    #[test]
+    fn test_typed_damage_reduction_only_against_matching_attacker() {
+        let mut state = State::default();
+        // Pikachu is weak to Fighting, so neither attacker triggers weakness
+        let mut defender = to_playable_card(&get_card_by_enum(CardId::A1094Pikachu), false);
+        defender.add_effect(
+            CardEffect::TypedDamageReduction {
+                attacker_type: EnergyType::Fire,
+                amount: 20,
+            },
+            1,
+        );
+        state.in_play_pokemon[1][0] = Some(defender);
+
+        let charmander = get_card_by_enum(CardId::A1033Charmander);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&charmander, false));
+        assert_eq!(final_damage(&state, (0, 0), (50, 1, 0), true), 30);
+
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&squirtle, false));
+        assert_eq!(final_damage(&state, (0, 0), (50, 1, 0), true), 50);
+    }
+
+    #[test]
+    fn test_heavy_helmet_only_protects_heavy_retreaters() {
+        let mut state = State::default();
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&squirtle, false));
+        // Blastoise has a retreat cost of 3, Bulbasaur of 1
+        for (defender, expected) in [(CardId::A1055Blastoise, 30), (CardId::A1001Bulbasaur, 50)] {
+            let mut defender = to_playable_card(&get_card_by_enum(defender), false);
+            defender.attached_tools = vec![ToolId::B1219HeavyHelmet];
+            state.in_play_pokemon[1][0] = Some(defender);
+            assert_eq!(final_damage(&state, (0, 0), (50, 1, 0), true), expected);
+        }
+    }
+
+    #[test]
+    fn test_attack_debuff_lowers_attackers_damage() {
+        let mut state = State::default();
+        let mut squirtle = to_playable_card(&get_card_by_enum(CardId::A1053Squirtle), false);
+        squirtle.add_effect(CardEffect::AttackDamageDebuff { amount: 20 }, 1);
+        state.in_play_pokemon[0][0] = Some(squirtle);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasaur, false));
+        assert_eq!(final_damage(&state, (0, 0), (50, 1, 0), true), 30);
+        assert_eq!(final_damage(&state, (0, 0), (10, 1, 0), true), 0);
+    }
+
+    #[test]
+    fn test_modifiers_combine_in_order() {
+        let mut state = State::default();
+        let charmander = get_card_by_enum(CardId::A1033Charmander);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&charmander, false));
+        // Bulbasaur is weak to Fire
+        let mut defender = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        defender.add_effect(CardEffect::ReducedDamage { amount: 30 }, 1);
+        state.in_play_pokemon[1][0] = Some(defender);
+        state.add_turn_effect(TurnEffect::IncreasedDamage { amount: 10 }, 0);
+
+        // 40 base + 20 weakness + 10 Giovanni - 30 reduction
+        assert_eq!(final_damage(&state, (0, 0), (40, 1, 0), true), 40);
+        // Base 0 isn't raised by any boost
+        assert_eq!(final_damage(&state, (0, 0), (0, 1, 0), true), 0);
+    }
+
+    #[test]
+    fn test_boosts_are_added_before_resistance() {
+        let mut state = State::default();
+        let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&squirtle, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&water_resistant_bulbasaur(), false));
+        state.add_turn_effect(TurnEffect::IncreasedDamage { amount: 10 }, 0);
+
+        // (10 + 10 Giovanni) - 20 resistance, rather than 10 - 20 floored, then + 10
+        assert_eq!(final_damage(&state, (0, 0), (10, 1, 0), true), 0);
+    }
+}
@@ -3,6 +3,7 @@
 /// We call these "hooks" (like on_attach_tool, on_attach_energy, on_play, on_knockout, etc...).
 mod core;
 mod counterattack;
+mod damage;
 mod retreat;
 
 pub(crate) use core::can_evolve_into;
@@ -12,15 +13,17 @@ pub(crate) use core::contains_energy;
 pub(crate) use core::energy_missing;
 pub(crate) use core::get_attack_cost;
 pub(crate) use core::get_stage;
+pub(crate) use core::get_tool_hp_bonus;
 pub(crate) use core::is_ultra_beast;
-pub(crate) use core::modify_damage;
 pub(crate) use core::on_attach_energy;
 pub(crate) use core::on_attach_tool;
+pub(crate) use core::on_detach_tool;
 pub(crate) use core::on_end_turn;
 pub(crate) use core::on_evolve;
 pub(crate) use core::on_knockout;
 pub(crate) use core::to_playable_card;
 pub(crate) use counterattack::get_counterattack_damage;
 pub(crate) use counterattack::should_poison_attacker;
+pub(crate) use damage::{compute_final_damage, DamageContext};
 pub(crate) use retreat::can_retreat;
 pub(crate) use retreat::get_retreat_cost;
@@ -7,19 +7,20 @@ use crate::{
 
 pub(crate) fn can_retreat(state: &State) -> bool {
     // Get Active card of the current player, check it has no CardEffect::NoRetreat
-    let has_no_retreat = state
-        .get_active(state.current_player)
-        .get_active_effects()
-        .contains(&CardEffect::NoRetreat);
+    let active = state.get_active(state.current_player);
+    let has_no_retreat = active.get_active_effects().contains(&CardEffect::NoRetreat);
 
-    !state.has_retreated && !has_no_retreat
+    !state.has_retreated && !has_no_retreat && !active.asleep && !active.paralyzed
 }
 
 pub(crate) fn get_retreat_cost(state: &State, card: &PlayedCard) -> Vec<EnergyType> {
+    if card.get_active_effects().contains(&CardEffect::FreeRetreat) {
+        return vec![];
+    }
     if let Card::Pokemon(pokemon_card) = &card.card {
         let mut normal_cost = pokemon_card.retreat_cost.clone();
-        if let Some(tool_id) = card.attached_tool {
-            if tool_id == ToolId::A4a067InflatableBoat
+        for tool_id in &card.attached_tools {
+            if *tool_id == ToolId::A4a067InflatableBoat
                 && card.get_energy_type() == Some(EnergyType::Water)
             {
                 normal_cost.pop();
@@ -102,7 +103,7 @@ mod tests {
         let state = State::default();
         let card = get_card_by_enum(CardId::A1055Blastoise);
         let mut playable_card = to_playable_card(&card, false);
-        playable_card.attached_tool = Some(ToolId::A4a067InflatableBoat);
+        playable_card.attached_tools = vec![ToolId::A4a067InflatableBoat];
         let retreat_cost = get_retreat_cost(&state, &playable_card);
         assert_eq!(
             retreat_cost,
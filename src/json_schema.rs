@@ -0,0 +1,37 @@
+use schemars::gen::SchemaGenerator;
+
+use crate::{actions::Action, actions::SimpleAction, models::PlayedCard, State};
+
+/// Returns a JSON Schema document describing the serialized `State`, with `Action`,
+/// `SimpleAction` and `PlayedCard` (and everything they reference) under `definitions`,
+/// so that clients can generate matching types (e.g. TypeScript for the WASM build).
+pub fn export_json_schema() -> String {
+    let mut generator = SchemaGenerator::default();
+    generator.subschema_for::<Action>();
+    generator.subschema_for::<SimpleAction>();
+    generator.subschema_for::<PlayedCard>();
+    let schema = generator.into_root_schema_for::<State>();
+    serde_json::to_string_pretty(&schema).expect("JSON Schema should serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_json_schema_has_top_level_definitions() {
+        let schema: serde_json::Value = serde_json::from_str(&export_json_schema()).unwrap();
+        assert_eq!(schema["title"], "State");
+        assert!(schema["properties"]["in_play_pokemon"].is_object());
+        for name in ["Action", "SimpleAction", "PlayedCard", "Card", "EnergyType"] {
+            assert!(
+                schema["definitions"][name].is_object(),
+                "Missing definition for {name}"
+            );
+        }
+        // PlayedCard's private effects serialize as [effect, turns_left] pairs
+        let effects = &schema["definitions"]["PlayedCard"]["properties"]["effects"];
+        assert_eq!(effects["type"], "array");
+        assert_eq!(effects["items"]["type"], "array");
+    }
+}
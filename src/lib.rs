@@ -14,11 +14,13 @@ pub mod models;
 pub mod move_generation;
 pub mod optimize;
 pub mod players;
+pub mod replay;
 pub mod simulate;
 pub mod simulation_event_handler;
 pub mod state;
 pub mod test_helpers; // TODO: Compile/Expose only in test mode?
 pub mod tool_ids;
+pub mod tournament;
 
 pub use ability_ids::AbilityId;
 pub use attack_ids::AttackId;
@@ -27,8 +29,10 @@ pub use game::Game;
 pub use move_generation::generate_possible_actions;
 pub use move_generation::generate_possible_trainer_actions;
 pub use optimize::{cli_optimize, optimize, ParallelConfig, SimulationConfig};
+pub use replay::Replay;
 pub use simulate::{simulate, Simulation};
 pub use state::State;
+pub use tournament::{run_matchup, run_round_robin, Standings};
 
 #[cfg(feature = "python")]
 pub mod python_bindings;
@@ -36,6 +40,9 @@ pub mod python_bindings;
 #[cfg(feature = "tui")]
 pub mod tui;
 
+#[cfg(feature = "json-schema")]
+pub mod json_schema;
+
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
@@ -3,10 +3,11 @@ use std::hash::{Hash, Hasher};
 
 use serde::{Deserialize, Serialize};
 
-use crate::card_ids::CardId;
+use crate::{card_ids::CardId, tool_ids::ToolId};
 
 /// Represents the type of energy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum EnergyType {
     Grass,
     Fire,
@@ -38,16 +39,38 @@ impl EnergyType {
 }
 
 /// Represents an attack of a card.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Attack {
     pub energy_required: Vec<EnergyType>,
     pub title: String,
     pub fixed_damage: u32,
     pub effect: Option<String>,
+    /// Can't be used during the user's first turn.
+    #[serde(default)]
+    pub first_turn_restricted: bool,
+    /// Can only be used while this Pokémon Tool is attached.
+    #[serde(default)]
+    pub required_tool: Option<ToolId>,
+    /// A coin flip this attack resolves with, on top of its `fixed_damage`.
+    #[serde(default)]
+    pub coin_flip: Option<AttackCoinFlip>,
+}
+
+/// Coin flips that resolve the same way whichever attack has them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AttackCoinFlip {
+    /// Flip a coin. If tails, this Pokémon is Knocked Out.
+    SelfKnockOutOnTails,
+    /// Flip a coin. If heads, this attack also does `bench_damage` to 1 of the opponent's
+    /// Benched Pokémon.
+    BenchHitOnHeads { bench_damage: u32 },
 }
 
 /// Represents an attack of a card.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Ability {
     pub title: String,
     pub effect: String,
@@ -57,6 +80,7 @@ pub const BASIC_STAGE: u8 = 0;
 
 /// Represents the data of a single pokemon card.
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PokemonCard {
     pub id: String,
     pub name: String,
@@ -67,10 +91,32 @@ pub struct PokemonCard {
     pub ability: Option<Ability>,
     pub attacks: Vec<Attack>,
     pub weakness: Option<EnergyType>,
+    #[serde(default)]
+    pub resistance: Option<EnergyType>,
     pub retreat_cost: Vec<EnergyType>,
     pub rarity: String,
     pub booster_pack: String,
 }
+impl Default for PokemonCard {
+    /// A blank Basic Pokémon. Lets card literals leave out optional fields such as `resistance`.
+    fn default() -> Self {
+        PokemonCard {
+            id: String::new(),
+            name: String::new(),
+            stage: 0,
+            evolves_from: None,
+            hp: 0,
+            energy_type: EnergyType::Colorless,
+            ability: None,
+            attacks: vec![],
+            weakness: None,
+            resistance: None,
+            retreat_cost: vec![],
+            rarity: String::new(),
+            booster_pack: String::new(),
+        }
+    }
+}
 impl PartialEq for PokemonCard {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -84,6 +130,7 @@ impl Hash for PokemonCard {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum TrainerType {
     Supporter,
     Item,
@@ -93,6 +140,7 @@ pub enum TrainerType {
 
 /// Represents the data of a single trainer card.
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TrainerCard {
     pub id: String,
     pub trainer_card_type: TrainerType,
@@ -114,6 +162,7 @@ impl Hash for TrainerCard {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum Card {
     Pokemon(PokemonCard),
     Trainer(TrainerCard),
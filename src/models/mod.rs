@@ -2,7 +2,7 @@ mod card;
 mod played_card;
 
 pub use card::{
-    Ability, Attack, Card, EnergyType, PokemonCard, StatusCondition, TrainerCard, TrainerType,
-    BASIC_STAGE,
+    Ability, Attack, AttackCoinFlip, Card, EnergyType, PokemonCard, StatusCondition, TrainerCard,
+    TrainerType, BASIC_STAGE,
 };
 pub use played_card::{has_serperior_jungle_totem, PlayedCard};
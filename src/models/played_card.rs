@@ -1,6 +1,6 @@
 use core::fmt;
 use log::debug;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
     card_ids::CardId,
@@ -13,12 +13,22 @@ use crate::{
 /// This represents a card in the mat. Has a pointer to the card
 /// description, but captures the extra variable properties while in mat.
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PlayedCard {
     pub card: Card,
     pub remaining_hp: u32,
     pub total_hp: u32,
     pub attached_energy: Vec<EnergyType>,
-    pub attached_tool: Option<ToolId>,
+    /// Older snapshots stored a single `attached_tool` (null or one tool); those still load.
+    #[serde(
+        default,
+        alias = "attached_tool",
+        deserialize_with = "deserialize_attached_tools"
+    )]
+    pub attached_tools: Vec<ToolId>,
+    /// How many tools this Pokémon can hold at once.
+    #[serde(default = "default_tool_capacity")]
+    pub tool_capacity: usize,
     pub played_this_turn: bool,
     pub ability_used: bool,
     pub poisoned: bool,
@@ -29,6 +39,8 @@ pub struct PlayedCard {
 
     /// Effects that should be cleared if moved to the bench (by retreat or similar).
     /// The second value is the number of turns left for the effect.
+    /// Serialized as a list of [effect, turns_left] pairs so it round-trips through JSON.
+    #[serde(default)]
     effects: Vec<(CardEffect, u8)>,
 }
 impl PlayedCard {
@@ -48,7 +60,8 @@ impl PlayedCard {
             played_this_turn,
             cards_behind,
 
-            attached_tool: None,
+            attached_tools: vec![],
+            tool_capacity: default_tool_capacity(),
             ability_used: false,
             poisoned: false,
             paralyzed: false,
@@ -79,8 +92,18 @@ impl PlayedCard {
         }
     }
 
+    /// Max HP, including HP from attached tools (e.g. Giant Cape). Tool bonuses are kept in
+    /// `total_hp` as they are attached/detached, so they must not be added again on top.
+    pub fn effective_total_hp(&self) -> u32 {
+        self.total_hp
+    }
+
     pub(crate) fn heal(&mut self, amount: u32) {
-        self.remaining_hp = (self.remaining_hp + amount).min(self.total_hp);
+        self.remaining_hp = (self.remaining_hp + amount).min(self.effective_total_hp());
+    }
+
+    pub(crate) fn full_heal(&mut self) {
+        self.remaining_hp = self.effective_total_hp();
     }
 
     pub(crate) fn attach_energy(&mut self, energy: &EnergyType, amount: u8) {
@@ -111,15 +134,24 @@ impl PlayedCard {
     }
 
     pub(crate) fn is_damaged(&self) -> bool {
-        self.remaining_hp < self.total_hp
+        self.remaining_hp < self.effective_total_hp()
     }
 
     pub(crate) fn has_status_condition(&self) -> bool {
-        self.poisoned || self.paralyzed || self.asleep
+        self.poisoned || self.paralyzed || self.asleep || self.burned
     }
 
     pub(crate) fn has_tool_attached(&self) -> bool {
-        self.attached_tool.is_some()
+        !self.attached_tools.is_empty()
+    }
+
+    pub(crate) fn has_tool(&self, tool_id: ToolId) -> bool {
+        self.attached_tools.contains(&tool_id)
+    }
+
+    /// Whether there is a free tool slot left on this Pokémon.
+    pub(crate) fn can_attach_tool(&self) -> bool {
+        self.attached_tools.len() < self.tool_capacity
     }
 
     /// Duration means:
@@ -131,7 +163,11 @@ impl PlayedCard {
     }
 
     pub(crate) fn get_active_effects(&self) -> Vec<CardEffect> {
-        self.effects.iter().map(|(effect, _)| *effect).collect()
+        self.iter_active_effects().collect()
+    }
+
+    pub(crate) fn iter_active_effects(&self) -> impl Iterator<Item = CardEffect> + '_ {
+        self.effects.iter().map(|(effect, _)| *effect)
     }
 
     pub(crate) fn clear_status_and_effects(&mut self) {
@@ -211,6 +247,11 @@ impl PlayedCard {
         }
     }
 
+    /// Number of energies this Pokemon counts as having, i.e. with Jungle Totem's doubled Grass.
+    pub(crate) fn effective_energy_count(&self, state: &State, player: usize) -> usize {
+        self.get_effective_attached_energy(state, player).len()
+    }
+
     pub(crate) fn has_double_grass(&self, state: &State, player: usize) -> bool {
         let pokemon_type = self.card.get_type();
         let jungle_totem_active = has_serperior_jungle_totem(state, player);
@@ -240,6 +281,27 @@ impl fmt::Debug for PlayedCard {
     }
 }
 
+fn default_tool_capacity() -> usize {
+    1
+}
+
+fn deserialize_attached_tools<'de, D>(deserializer: D) -> Result<Vec<ToolId>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AttachedTools {
+        Many(Vec<ToolId>),
+        One(Option<ToolId>),
+    }
+
+    Ok(match AttachedTools::deserialize(deserializer)? {
+        AttachedTools::Many(tools) => tools,
+        AttachedTools::One(tool) => tool.into_iter().collect(),
+    })
+}
+
 pub fn has_serperior_jungle_totem(state: &crate::state::State, player: usize) -> bool {
     state.enumerate_in_play_pokemon(player).any(|(_, pokemon)| {
         AbilityId::from_pokemon_id(&pokemon.get_id()[..])
@@ -251,8 +313,12 @@ pub fn has_serperior_jungle_totem(state: &crate::state::State, player: usize) ->
 #[cfg(test)]
 mod tests {
     use crate::{
-        card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card,
-        models::has_serperior_jungle_totem, state::State,
+        card_ids::CardId,
+        database::get_card_by_enum,
+        hooks::{on_attach_tool, to_playable_card},
+        models::{has_serperior_jungle_totem, EnergyType, PlayedCard},
+        state::State,
+        tool_ids::ToolId,
     };
 
     #[test]
@@ -305,4 +371,95 @@ mod tests {
             "Should not detect Jungle Totem ability for opponent player"
         );
     }
+
+    #[test]
+    fn test_effective_total_hp_includes_giant_cape() {
+        let mut state = State::default();
+        let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut bulbasaur = to_playable_card(&bulbasaur_card, false);
+        bulbasaur.attached_tools = vec![ToolId::A2147GiantCape];
+        state.in_play_pokemon[0][0] = Some(bulbasaur);
+        on_attach_tool(&mut state, 0, 0, ToolId::A2147GiantCape);
+
+        let bulbasaur = state.in_play_pokemon[0][0].as_mut().unwrap();
+        assert_eq!(bulbasaur.effective_total_hp(), 90); // 70 + 20
+        bulbasaur.apply_damage(50);
+        bulbasaur.heal(100);
+        assert_eq!(bulbasaur.remaining_hp, 90);
+    }
+
+    #[test]
+    fn test_full_heal_restores_total_hp() {
+        let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut bulbasaur = to_playable_card(&bulbasaur_card, false);
+        bulbasaur.apply_damage(50);
+        assert!(bulbasaur.is_damaged());
+
+        bulbasaur.full_heal();
+
+        assert_eq!(bulbasaur.remaining_hp, bulbasaur.total_hp);
+        assert!(!bulbasaur.is_damaged());
+    }
+
+    #[test]
+    fn test_two_serperiors_only_double_grass_energy() {
+        // Arrange: Two Serperiors in play, and a Grass Pokemon with 2 Grass + 1 Water energy
+        let mut state = State::default();
+        let serperior_card = get_card_by_enum(CardId::A1a006Serperior);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&serperior_card, false));
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&serperior_card, false));
+        let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut bulbasaur = to_playable_card(&bulbasaur_card, false);
+        bulbasaur.attached_energy = vec![EnergyType::Grass, EnergyType::Grass, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+
+        // Act
+        let effective = bulbasaur.get_effective_attached_energy(&state, 0);
+
+        // Assert: Jungle Totem doesn't stack, so Grass counts exactly double
+        let grass_count = effective
+            .iter()
+            .filter(|e| **e == EnergyType::Grass)
+            .count();
+        assert_eq!(grass_count, 4, "Grass energy should be doubled, not more");
+        assert_eq!(effective.len(), 5);
+    }
+
+    #[test]
+    fn test_effective_energy_count_with_and_without_serperior() {
+        let mut state = State::default();
+        let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut bulbasaur = to_playable_card(&bulbasaur_card, false);
+        bulbasaur.attached_energy = vec![EnergyType::Grass, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        assert_eq!(bulbasaur.effective_energy_count(&state, 0), 2);
+
+        let serperior_card = get_card_by_enum(CardId::A1a006Serperior);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&serperior_card, false));
+        assert_eq!(bulbasaur.effective_energy_count(&state, 0), 3);
+        // Jungle Totem only helps its owner's Pokemon
+        assert_eq!(bulbasaur.effective_energy_count(&state, 1), 2);
+    }
+
+    #[test]
+    fn test_legacy_single_attached_tool_deserializes() {
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        let mut json = serde_json::to_value(&bulbasaur).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("attached_tools");
+        fields.remove("tool_capacity");
+
+        fields.insert(
+            "attached_tool".into(),
+            serde_json::json!("A2148RockyHelmet"),
+        );
+        let legacy: PlayedCard = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(legacy.attached_tools, vec![ToolId::A2148RockyHelmet]);
+        assert_eq!(legacy.tool_capacity, 1);
+
+        let fields = json.as_object_mut().unwrap();
+        fields.insert("attached_tool".into(), serde_json::Value::Null);
+        let legacy: PlayedCard = serde_json::from_value(json).unwrap();
+        assert!(legacy.attached_tools.is_empty());
+    }
 }
@@ -3,44 +3,124 @@ use crate::{
     attack_ids::AttackId,
     effects::CardEffect,
     hooks::{contains_energy, get_attack_cost},
+    models::PlayedCard,
     State,
 };
 
 pub(crate) fn generate_attack_actions(state: &State) -> Vec<SimpleAction> {
     let current_player = state.current_player;
     let mut actions = Vec::new();
-    if let Some(active_pokemon) = &state.in_play_pokemon[current_player][0] {
-        // Check if the active Pokémon has the CannotAttack effect
-        let active_effects = active_pokemon.get_active_effects();
-        let cannot_attack = active_effects
-            .iter()
-            .any(|effect| matches!(effect, CardEffect::CannotAttack));
-        if cannot_attack {
-            return actions;
+    let Some(active_pokemon) = &state.in_play_pokemon[current_player][0] else {
+        return actions;
+    };
+    if active_pokemon.asleep || active_pokemon.paralyzed {
+        return actions;
+    }
+
+    // This runs on every move generation, so effects are scanned in place instead of
+    // collected, and attack ids are only looked up if some attack is restricted.
+    let mut has_restricted_attacks = false;
+    for effect in active_pokemon.iter_active_effects() {
+        match effect {
+            CardEffect::CannotAttack => return actions,
+            CardEffect::CannotUseAttack(_) => has_restricted_attacks = true,
+            _ => {}
         }
+    }
 
-        let restricted_attacks: Vec<AttackId> = active_effects
-            .iter()
-            .filter_map(|effect| match effect {
-                CardEffect::CannotUseAttack(attack_id) => Some(*attack_id),
-                _ => None,
-            })
-            .collect();
-
-        let pokemon_id = active_pokemon.get_id();
-        for (i, attack) in active_pokemon.get_attacks().iter().enumerate() {
-            let modified_cost = get_attack_cost(&attack.energy_required, state, current_player);
-            if contains_energy(active_pokemon, &modified_cost, state, current_player) {
-                let attack_is_restricted = AttackId::from_pokemon_index(&pokemon_id[..], i)
-                    .map(|attack_id| restricted_attacks.contains(&attack_id))
-                    .unwrap_or(false);
-                if attack_is_restricted {
-                    continue;
-                }
-
-                actions.push(SimpleAction::Attack(i));
-            }
+    let is_users_first_turn = state.is_users_first_turn();
+    for (i, attack) in active_pokemon.get_attacks().iter().enumerate() {
+        if is_users_first_turn && attack.first_turn_restricted {
+            continue;
+        }
+        if attack
+            .required_tool
+            .is_some_and(|tool| !active_pokemon.has_tool(tool))
+        {
+            continue;
+        }
+        if has_restricted_attacks && is_restricted(active_pokemon, i) {
+            continue;
+        }
+        let modified_cost = get_attack_cost(&attack.energy_required, state, current_player);
+        if contains_energy(active_pokemon, &modified_cost, state, current_player) {
+            actions.push(SimpleAction::Attack(i));
         }
     }
     actions
 }
+
+/// Whether a CannotUseAttack effect forbids the attack at `index`.
+fn is_restricted(pokemon: &PlayedCard, index: usize) -> bool {
+    let Some(attack_id) = AttackId::from_pokemon_index(&pokemon.get_id(), index) else {
+        return false;
+    };
+    pokemon
+        .iter_active_effects()
+        .any(|effect| effect == CardEffect::CannotUseAttack(attack_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId,
+        database::get_card_by_enum,
+        hooks::to_playable_card,
+        models::{Card, EnergyType},
+        tool_ids::ToolId,
+    };
+
+    #[test]
+    fn test_first_turn_restricted_attack_only_available_after_first_turn() {
+        // No printed card has this restriction yet, so flag Bulbasaur's Vine Whip
+        let mut card = get_card_by_enum(CardId::A1001Bulbasaur);
+        if let Card::Pokemon(pokemon_card) = &mut card {
+            pokemon_card.attacks[0].first_turn_restricted = true;
+        }
+        let mut active = to_playable_card(&card, false);
+        active.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(active);
+        state.turn_count = 2;
+        assert!(generate_attack_actions(&state).is_empty());
+
+        state.turn_count = 3;
+        assert_eq!(
+            generate_attack_actions(&state),
+            vec![SimpleAction::Attack(0)]
+        );
+    }
+
+    #[test]
+    fn test_tool_locked_attack_only_available_with_tool() {
+        // No printed card needs a tool to attack yet, so lock Bulbasaur's Vine Whip
+        let mut card = get_card_by_enum(CardId::A1001Bulbasaur);
+        if let Card::Pokemon(pokemon_card) = &mut card {
+            pokemon_card.attacks[0].required_tool = Some(ToolId::A2148RockyHelmet);
+        }
+        let mut active = to_playable_card(&card, false);
+        active.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let mut state = State::default();
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(
+            &get_card_by_enum(CardId::A1003Venusaur),
+            false,
+        ));
+        state.turn_count = 3;
+        assert!(generate_attack_actions(&state).is_empty());
+
+        state.get_active_mut(0).attached_tools = vec![ToolId::A2147GiantCape];
+        assert!(generate_attack_actions(&state).is_empty());
+
+        state.get_active_mut(0).attached_tools = vec![ToolId::A2148RockyHelmet];
+        assert_eq!(
+            generate_attack_actions(&state),
+            vec![SimpleAction::Attack(0)]
+        );
+        let action = crate::actions::Action::new(0, SimpleAction::Attack(0), false);
+        let mut rng = rand::SeedableRng::seed_from_u64(0);
+        crate::actions::apply_action(&mut rng, &mut state, &action);
+        assert_eq!(state.get_remaining_hp(1, 0), 120);
+    }
+}
@@ -24,12 +24,8 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     let in_initial_setup_phase = state.turn_count == 0;
     if in_initial_setup_phase {
         let possible_actions = generate_initial_setup_actions(state)
-            .iter()
-            .map(|action| Action {
-                actor: state.current_player,
-                action: action.clone(),
-                is_stack: false,
-            })
+            .into_iter()
+            .map(|action| Action::new(state.current_player, action, false))
             .collect();
         return (state.current_player, possible_actions);
     }
@@ -38,11 +34,7 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     if let Some((actor, possible_actions)) = state.move_generation_stack.last() {
         let actions = possible_actions
             .iter()
-            .map(|action| Action {
-                actor: *actor,
-                action: action.clone(),
-                is_stack: true,
-            })
+            .map(|action| Action::new(*actor, action.clone(), true))
             .collect();
         return (*actor, actions);
     }
@@ -91,20 +83,41 @@ pub fn generate_possible_actions(state: &State) -> (usize, Vec<Action>) {
     let ability_actions = generate_ability_actions(state);
     actions.extend(ability_actions);
 
+    // Stable, so ties keep generation order (i.e. by attack index, hand order or in-play index)
+    actions.sort_by_key(free_play_rank);
+
     let possible_actions = actions
-        .iter()
-        .map(|action| Action {
-            actor: current_player,
-            action: action.clone(),
-            is_stack: false,
-        })
+        .into_iter()
+        .map(|action| Action::new(current_player, action, false))
         .collect();
     (current_player, possible_actions)
 }
 
+/// The order free play actions are returned in, so that e.g. `actions[0]` is the first attack
+/// if any: Attack < UseAbility < Play < Evolve < Place < Attach < Retreat < EndTurn.
+fn free_play_rank(action: &SimpleAction) -> u8 {
+    match action {
+        SimpleAction::Attack(_) => 0,
+        SimpleAction::UseAbility { .. } => 1,
+        SimpleAction::Play { .. } => 2,
+        SimpleAction::Evolve(_, _) => 3,
+        SimpleAction::Place(_, _) => 4,
+        SimpleAction::Attach { .. } => 5,
+        SimpleAction::Retreat(_) => 6,
+        SimpleAction::EndTurn => u8::MAX,
+        // Other actions only come from the move generation stack
+        _ => 7,
+    }
+}
+
+/// During setup only Basic Pokémon may be placed, and the Active Spot must be filled
+/// before the player can end their setup.
 fn generate_initial_setup_actions(state: &State) -> Vec<SimpleAction> {
     let current_player = state.current_player;
-    let hand_actions = generate_hand_actions(state);
+    let hand_actions: Vec<SimpleAction> = generate_hand_actions(state)
+        .into_iter()
+        .filter(|x| matches!(x, SimpleAction::Place(card, _) if card.is_basic()))
+        .collect();
     if state.in_play_pokemon[current_player][0].is_none() {
         let place_active_actions: Vec<SimpleAction> = hand_actions
             .iter()
@@ -141,6 +154,10 @@ fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
             Card::Pokemon(pokemon_card) => {
                 // Basic pokemons can be placed in empty Active or Bench slots
                 if pokemon_card.stage == 0 {
+                    let has_active = state.maybe_get_active(current_player).is_some();
+                    if has_active && state.is_bench_full(current_player) {
+                        return;
+                    }
                     state.in_play_pokemon[current_player]
                         .iter()
                         .enumerate()
@@ -178,3 +195,55 @@ fn generate_hand_actions(state: &State) -> Vec<SimpleAction> {
 fn get_current_hand(state: &State) -> &Vec<Card> {
     &state.hands[state.current_player]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card, models::EnergyType,
+    };
+
+    #[test]
+    fn test_free_play_actions_are_ordered_by_kind() {
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        let poke_ball = get_card_by_enum(CardId::PA005PokeBall);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let mut state = State::default();
+        state.turn_count = 3;
+        state.current_energy = Some(EnergyType::Grass);
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasaur, false));
+        state.hands[0] = vec![bulbasaur.clone(), ivysaur.clone(), poke_ball.clone()];
+
+        let (actor, actions) = generate_possible_actions(&state);
+        let actions: Vec<SimpleAction> = actions.into_iter().map(|x| x.action).collect();
+
+        assert_eq!(actor, 0);
+        assert_eq!(
+            actions,
+            vec![
+                SimpleAction::Attack(0),
+                SimpleAction::Play {
+                    trainer_card: poke_ball.as_trainer()
+                },
+                SimpleAction::Evolve(ivysaur.clone(), 0),
+                SimpleAction::Evolve(ivysaur, 1),
+                SimpleAction::Place(bulbasaur.clone(), 2),
+                SimpleAction::Place(bulbasaur, 3),
+                SimpleAction::Attach {
+                    attachments: vec![(1, EnergyType::Grass, 0)],
+                    is_turn_energy: true,
+                },
+                SimpleAction::Attach {
+                    attachments: vec![(1, EnergyType::Grass, 1)],
+                    is_turn_energy: true,
+                },
+                SimpleAction::Retreat(1),
+                SimpleAction::EndTurn,
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     actions::SimpleAction,
     card_ids::CardId,
-    card_logic::can_rare_candy_evolve,
+    card_logic::{can_rare_candy_evolve, is_mallow_target, is_silvally, is_type_null},
     hooks::{can_play_item, can_play_support, get_stage, is_ultra_beast},
     models::{Card, EnergyType, TrainerCard, TrainerType},
     tool_ids::ToolId,
@@ -60,6 +60,10 @@ pub fn trainer_move_generation_implementation(
         CardId::A2a072Irida | CardId::A2a087Irida | CardId::A4b330Irida | CardId::A4b331Irida => {
             can_play_irida(state, trainer_card)
         }
+        CardId::A3151Guzma | CardId::A3193Guzma | CardId::A3208Guzma => {
+            can_play_guzma(state, trainer_card)
+        }
+        CardId::A3154Mallow | CardId::A3196Mallow => can_play_mallow(state, trainer_card),
         CardId::A3155Lillie
         | CardId::A3197Lillie
         | CardId::A3209Lillie
@@ -74,6 +78,9 @@ pub fn trainer_move_generation_implementation(
         CardId::A2150Cyrus | CardId::A2190Cyrus | CardId::A4b326Cyrus | CardId::A4b327Cyrus => {
             can_play_cyrus(state, trainer_card)
         }
+        CardId::A2154Dawn | CardId::A2194Dawn | CardId::A4b342Dawn | CardId::A4b343Dawn => {
+            can_play_dawn(state, trainer_card)
+        }
         CardId::A2155Mars | CardId::A2195Mars | CardId::A4b344Mars | CardId::A4b345Mars => {
             can_play_trainer(state, trainer_card)
         }
@@ -116,6 +123,8 @@ pub fn trainer_move_generation_implementation(
         | CardId::A4b334Giovanni
         | CardId::A4b335Giovanni
         | CardId::A1a065MythicalSlab
+        | CardId::A3a068Looker
+        | CardId::A3a082Looker
         | CardId::A1a068Leaf
         | CardId::A1a082Leaf
         | CardId::A4b346Leaf
@@ -160,8 +169,7 @@ fn can_play_tool(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simple
 /// Check if Potion can be played (requires at least 1 damaged pokemon in play)
 fn can_play_potion(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let damaged_count = state
-        .enumerate_in_play_pokemon(state.current_player)
-        .filter(|(_, x)| x.is_damaged())
+        .enumerate_damaged_pokemon(state.current_player)
         .count();
     if damaged_count > 0 {
         can_play_trainer(state, trainer_card)
@@ -173,8 +181,8 @@ fn can_play_potion(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simp
 /// Check if Erika can be played (requires at least 1 damaged Grass pokemon in play)
 fn can_play_erika(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let damaged_grass_count = state
-        .enumerate_in_play_pokemon(state.current_player)
-        .filter(|(_, x)| x.is_damaged() && x.get_energy_type() == Some(EnergyType::Grass))
+        .enumerate_damaged_pokemon(state.current_player)
+        .filter(|(_, x)| x.get_energy_type() == Some(EnergyType::Grass))
         .count();
     if damaged_grass_count > 0 {
         can_play_trainer(state, trainer_card)
@@ -186,8 +194,8 @@ fn can_play_erika(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simpl
 /// Check if Irida can be played (requires at least 1 damaged pokemon with Water energy attached)
 fn can_play_irida(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let damaged_water_count = state
-        .enumerate_in_play_pokemon(state.current_player)
-        .filter(|(_, x)| x.is_damaged() && x.attached_energy.contains(&EnergyType::Water))
+        .enumerate_damaged_pokemon(state.current_player)
+        .filter(|(_, x)| x.attached_energy.contains(&EnergyType::Water))
         .count();
     if damaged_water_count > 0 {
         can_play_trainer(state, trainer_card)
@@ -221,6 +229,22 @@ fn can_play_elemental_switch(
     }
 }
 
+/// Check if Dawn can be played (requires at least 1 Benched Pokémon with energy attached)
+fn can_play_dawn(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
+    if state.maybe_get_active(state.current_player).is_none() {
+        return cannot_play_trainer();
+    }
+    let has_valid_source = state
+        .enumerate_bench_pokemon(state.current_player)
+        .any(|(_, pokemon)| !pokemon.attached_energy.is_empty());
+
+    if has_valid_source {
+        can_play_trainer(state, trainer_card)
+    } else {
+        cannot_play_trainer()
+    }
+}
+
 /// Check if Pokemon Center Lady can be played (requires at least 1 damaged or status-affected pokemon)
 fn can_play_pokemon_center_lady(
     state: &State,
@@ -236,11 +260,23 @@ fn can_play_pokemon_center_lady(
     }
 }
 
+/// Check if Mallow can be played (requires at least 1 damaged Shiinotic or Tsareena in play)
+fn can_play_mallow(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
+    let has_target = state
+        .enumerate_damaged_pokemon(state.current_player)
+        .any(|(_, x)| is_mallow_target(x));
+    if has_target {
+        can_play_trainer(state, trainer_card)
+    } else {
+        cannot_play_trainer()
+    }
+}
+
 /// Check if Lillie can be played (requires at least 1 damaged Stage 2 pokemon in play)
 fn can_play_lillie(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let damaged_stage2_count = state
-        .enumerate_in_play_pokemon(state.current_player)
-        .filter(|(_, x)| x.is_damaged() && get_stage(x) == 2)
+        .enumerate_damaged_pokemon(state.current_player)
+        .filter(|(_, x)| get_stage(x) == 2)
         .count();
     if damaged_stage2_count > 0 {
         can_play_trainer(state, trainer_card)
@@ -259,8 +295,12 @@ fn can_play_misty(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simpl
     }
 }
 
-/// Check if Koga can be played (requires active pokemon to be Weezing or Muk)
+/// Check if Koga can be played (requires active pokemon to be Weezing or Muk, and a
+/// benched pokemon to promote afterwards)
 fn can_play_koga(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
+    if state.enumerate_bench_pokemon(state.current_player).count() == 0 {
+        return cannot_play_trainer();
+    }
     let active_pokemon = &state.maybe_get_active(state.current_player);
     if let Some(played_card) = active_pokemon {
         let card_id =
@@ -277,7 +317,7 @@ fn can_play_koga(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simple
 
 /// Check if Sabrina can be played (requires opponent to have benched pokemon)
 fn can_play_sabrina(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let opponent_has_bench = state.enumerate_bench_pokemon(opponent).count() > 0;
     if opponent_has_bench {
         can_play_trainer(state, trainer_card)
@@ -286,9 +326,22 @@ fn can_play_sabrina(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Sim
     }
 }
 
+/// Check if Guzma can be played (requires opponent to have a Pokémon with a tool attached)
+fn can_play_guzma(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
+    let opponent = state.opponent();
+    let opponent_has_tools = state
+        .enumerate_in_play_pokemon(opponent)
+        .any(|(_, x)| x.has_tool_attached());
+    if opponent_has_tools {
+        can_play_trainer(state, trainer_card)
+    } else {
+        cannot_play_trainer()
+    }
+}
+
 /// Check if Cyrus can be played (requires opponent to have at least 1 damaged bench pokemon)
 fn can_play_cyrus(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let damaged_bench_count = state
         .enumerate_bench_pokemon(opponent)
         .filter(|(_, x)| x.is_damaged())
@@ -302,7 +355,7 @@ fn can_play_cyrus(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Simpl
 
 /// Check if Repel can be played (requires opponent's active to be a Basic pokemon)
 fn can_play_repel(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
-    let opponent = (state.current_player + 1) % 2;
+    let opponent = state.opponent();
     let opponent_active = &state.maybe_get_active(opponent);
     let opponent_bench_count = state.enumerate_bench_pokemon(opponent).count();
     if let Some(opponent_active) = opponent_active {
@@ -356,37 +409,15 @@ fn can_play_pokemon_communication(
 fn can_play_gladion(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let player = state.current_player;
 
-    // Count Type: Null and Silvally in play and discard
-    let mut type_null_count = 0;
-    let mut silvally_count = 0;
-
-    // Count in play Pokemon (including cards_behind)
-    for pokemon in state.in_play_pokemon[player].iter().flatten() {
-        // Check the current card
-        if pokemon.get_name() == "Type: Null" {
-            type_null_count += 1;
-        } else if pokemon.get_name() == "Silvally" {
-            silvally_count += 1;
-        }
-
-        // Check cards_behind (evolution chain)
-        for card in &pokemon.cards_behind {
-            if card.get_name() == "Type: Null" {
-                type_null_count += 1;
-            } else if card.get_name() == "Silvally" {
-                silvally_count += 1;
-            }
-        }
-    }
-
-    // Count in discard pile
-    for card in &state.discard_piles[player] {
-        if card.get_name() == "Type: Null" {
-            type_null_count += 1;
-        } else if card.get_name() == "Silvally" {
-            silvally_count += 1;
-        }
-    }
+    // Count Type: Null and Silvally in play (including cards_behind) and discard
+    let accounted: Vec<&Card> = state.in_play_pokemon[player]
+        .iter()
+        .flatten()
+        .flat_map(|pokemon| std::iter::once(&pokemon.card).chain(&pokemon.cards_behind))
+        .chain(&state.discard_piles[player])
+        .collect();
+    let type_null_count = accounted.iter().filter(|card| is_type_null(card)).count();
+    let silvally_count = accounted.iter().filter(|card| is_silvally(card)).count();
 
     // Can play if we haven't accounted for all 2 Type: Null and 2 Silvally
     // (meaning there might still be some in the deck)
@@ -400,7 +431,7 @@ fn can_play_gladion(state: &State, trainer_card: &TrainerCard) -> Option<Vec<Sim
 /// Check if Lusamine can be played (requires opponent has >= 1 point, player has Ultra Beast, >= 1 energy in discard)
 fn can_play_lusamine(state: &State, trainer_card: &TrainerCard) -> Option<Vec<SimpleAction>> {
     let player = state.current_player;
-    let opponent = (player + 1) % 2;
+    let opponent = State::opponent_of(player);
 
     // Check if opponent has at least 1 point
     if state.points[opponent] < 1 {
@@ -10,13 +10,18 @@ use super::Player;
 
 /// A player that always tries to Attach energy to active Pokemon
 ///   if it can. If it can't, it will attack with the active Pokemon.
-/// Else it will just do the first possible action.
+/// Else it will end its turn (or, during setup, do the first possible action).
 pub struct AttachAttackPlayer {
     pub deck: Deck,
 }
 
 impl Player for AttachAttackPlayer {
-    fn decision_fn(&mut self, _: &mut StdRng, _: &State, possible_actions: &[Action]) -> Action {
+    fn decision_fn(
+        &mut self,
+        _: &mut StdRng,
+        state: &State,
+        possible_actions: &[Action],
+    ) -> Action {
         let maybe_attach = possible_actions
             .iter()
             .find(|action| matches!(action.action, SimpleAction::Attach { .. }));
@@ -29,6 +34,14 @@ impl Player for AttachAttackPlayer {
         if let Some(attack) = maybe_attack {
             return attack.clone();
         }
+        // End the turn, except during setup where the first actions bench more Basics
+        let maybe_end_turn = possible_actions
+            .iter()
+            .find(|action| matches!(action.action, SimpleAction::EndTurn))
+            .filter(|_| state.turn_count > 0);
+        if let Some(end_turn) = maybe_end_turn {
+            return end_turn.clone();
+        }
         possible_actions
             .first()
             .expect("There should always be at least one playable action")
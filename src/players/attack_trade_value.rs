@@ -0,0 +1,104 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    actions::{forecast_action, Action, SimpleAction},
+    State,
+};
+
+// How many HP a point is worth when trading it against damage.
+const POINT_VALUE: f64 = 100.0;
+
+/// Scores how good it is for the current player to use the attack at `attack_index`,
+/// weighting every forecasted outcome by its probability. Damage dealt (and points won by
+/// K.O.s) count in favour; damage taken back (e.g. Rocky Helmet, recoil), self K.O.s and
+/// the points they give the opponent count against.
+pub fn attack_trade_value(state: &State, attack_index: usize) -> i32 {
+    let myself = state.current_player;
+    let opponent = State::opponent_of(myself);
+    let action = Action::new(myself, SimpleAction::Attack(attack_index), false);
+
+    // Seeded so that the score is reproducible for outcomes that consume randomness.
+    let mut rng = StdRng::seed_from_u64(0);
+    let (probabilities, mutations) = forecast_action(state, &action);
+    let expected_value: f64 = probabilities
+        .iter()
+        .zip(mutations)
+        .map(|(probability, mutation)| {
+            let mut outcome = state.clone();
+            mutation(&mut rng, &mut outcome, &action);
+            let damage_dealt = hp_in_play(state, opponent) - hp_in_play(&outcome, opponent);
+            let damage_taken = hp_in_play(state, myself) - hp_in_play(&outcome, myself);
+            let points_won = outcome.points[myself] as f64 - state.points[myself] as f64;
+            let points_lost = outcome.points[opponent] as f64 - state.points[opponent] as f64;
+            let value = damage_dealt - damage_taken + (points_won - points_lost) * POINT_VALUE;
+            probability * value
+        })
+        .sum();
+    expected_value.round() as i32
+}
+
+// K.O.ed Pokémon leave play, so they count as having lost all their remaining HP.
+fn hp_in_play(state: &State, player: usize) -> f64 {
+    state
+        .enumerate_in_play_pokemon(player)
+        .map(|(_, pokemon)| pokemon.remaining_hp as f64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card_ids::CardId, database::get_card_by_enum, hooks::to_playable_card, models::PlayedCard,
+        tool_ids::ToolId,
+    };
+
+    fn state_with_defender(defender: PlayedCard) -> State {
+        let mut state = State::default();
+        let attacker = get_card_by_enum(CardId::A1001Bulbasaur); // Vine Whip: 40 damage
+        let venusaur = get_card_by_enum(CardId::A1003Venusaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&attacker, false));
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&attacker, false));
+        state.in_play_pokemon[1][0] = Some(defender);
+        state.in_play_pokemon[1][1] = Some(to_playable_card(&venusaur, false));
+        state
+    }
+
+    #[test]
+    fn test_clean_lethal_beats_counter_triggering_attack() {
+        let venusaur = get_card_by_enum(CardId::A1003Venusaur);
+        let mut weakened = to_playable_card(&venusaur, false);
+        weakened.remaining_hp = 30;
+        let lethal_state = state_with_defender(weakened);
+
+        let mut helmeted = to_playable_card(&venusaur, false);
+        helmeted.attached_tools = vec![ToolId::A2148RockyHelmet];
+        let helmet_state = state_with_defender(helmeted);
+
+        let lethal_value = attack_trade_value(&lethal_state, 0);
+        let helmet_value = attack_trade_value(&helmet_state, 0);
+        assert_eq!(lethal_value, 30 + 100);
+        assert_eq!(helmet_value, 40 - 20);
+        assert!(lethal_value > helmet_value);
+    }
+
+    #[test]
+    fn test_hitting_weakness_beats_equal_base_damage() {
+        let charmander = get_card_by_enum(CardId::A1033Charmander); // Weak to Water
+        let state_with_attacker = |attacker: CardId| {
+            let mut state = State::default();
+            state.in_play_pokemon[0][0] =
+                Some(to_playable_card(&get_card_by_enum(attacker), false));
+            state.in_play_pokemon[1][0] = Some(to_playable_card(&charmander, false));
+            state.in_play_pokemon[1][1] = Some(to_playable_card(&charmander, false));
+            state
+        };
+
+        // Water Gun and Gnaw both do 20 base damage
+        let water_gun_value = attack_trade_value(&state_with_attacker(CardId::A1053Squirtle), 0);
+        let gnaw_value = attack_trade_value(&state_with_attacker(CardId::A1094Pikachu), 0);
+        assert_eq!(water_gun_value, 20 + 20);
+        assert_eq!(gnaw_value, 20);
+        assert!(water_gun_value > gnaw_value);
+    }
+}
@@ -100,6 +100,14 @@ impl Player for EvolutionRusherPlayer {
         if let Some(attack) = maybe_attack {
             return attack.clone();
         }
+        // End the turn, except during setup where the first actions bench more Basics
+        let maybe_end_turn = possible_actions
+            .iter()
+            .find(|action| matches!(action.action, SimpleAction::EndTurn))
+            .filter(|_| state.turn_count > 0);
+        if let Some(end_turn) = maybe_end_turn {
+            return end_turn.clone();
+        }
         possible_actions
             .first()
             .expect("There should always be at least one playable action")
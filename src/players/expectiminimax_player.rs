@@ -202,7 +202,7 @@ fn expectiminimax(
 }
 
 fn value_function(state: &State, myself: usize) -> f64 {
-    let opponent = (myself + 1) % 2;
+    let opponent = State::opponent_of(myself);
     let active_factor = 2.0; // Weight for active pokemon
 
     // Points
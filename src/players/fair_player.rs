@@ -0,0 +1,42 @@
+use rand::rngs::StdRng;
+use std::fmt::Debug;
+
+use crate::{actions::Action, state::Observation, Deck, State};
+
+use super::Player;
+
+/// An agent that only decides on public information (see `State::observe`), so it can't
+/// peek at the opponent's hand or at the order of either deck.
+pub trait ObservingPlayer: Debug {
+    fn get_deck(&self) -> Deck;
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        observation: &Observation,
+        possible_actions: &[Action],
+    ) -> Action;
+}
+
+/// Adapts an `ObservingPlayer` into a `Player`, redacting the State before every decision.
+#[derive(Debug)]
+pub struct FairPlayer<P: ObservingPlayer>(pub P);
+
+impl<P: ObservingPlayer> Player for FairPlayer<P> {
+    fn decision_fn(
+        &mut self,
+        rng: &mut StdRng,
+        state: &State,
+        possible_actions: &[Action],
+    ) -> Action {
+        let actor = possible_actions
+            .first()
+            .expect("There should always be at least one playable action")
+            .actor;
+        self.0
+            .decision_fn(rng, &state.observe(actor), possible_actions)
+    }
+
+    fn get_deck(&self) -> Deck {
+        self.0.get_deck()
+    }
+}
@@ -1,20 +1,26 @@
 mod attach_attack_player;
+mod attack_trade_value;
 mod end_turn_player;
 mod evolution_rusher_player;
 mod expectiminimax_player;
+mod fair_player;
 mod human_player;
 mod mcts_player;
 mod random_player;
+mod scripted_player;
 mod value_function_player;
 mod weighted_random_player;
 
 pub use attach_attack_player::AttachAttackPlayer;
+pub use attack_trade_value::attack_trade_value;
 pub use end_turn_player::EndTurnPlayer;
 pub use evolution_rusher_player::EvolutionRusherPlayer;
 pub use expectiminimax_player::ExpectiMiniMaxPlayer;
+pub use fair_player::{FairPlayer, ObservingPlayer};
 pub use human_player::HumanPlayer;
 pub use mcts_player::MctsPlayer;
 pub use random_player::RandomPlayer;
+pub use scripted_player::ScriptedPlayer;
 pub use value_function_player::ValueFunctionPlayer;
 pub use weighted_random_player::WeightedRandomPlayer;
 
@@ -5,6 +5,8 @@ use crate::{actions::Action, Deck, State};
 
 use super::Player;
 
+/// Picks uniformly among the possible actions using the game's RNG, so games between
+/// `RandomPlayer`s are fully determined by the game seed. Handy as a fuzzing opponent.
 pub struct RandomPlayer {
     pub deck: Deck,
 }
@@ -0,0 +1,53 @@
+use log::warn;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use crate::{
+    actions::{Action, SimpleAction},
+    Deck, State,
+};
+
+use super::Player;
+
+/// A player that plays a fixed sequence of actions, for deterministic tests.
+/// When the script runs out or its next action isn't legal, it behaves like an EndTurnPlayer.
+pub struct ScriptedPlayer {
+    pub deck: Deck,
+    pub actions: VecDeque<SimpleAction>,
+}
+
+impl Player for ScriptedPlayer {
+    fn decision_fn(&mut self, _: &mut StdRng, _: &State, possible_actions: &[Action]) -> Action {
+        if let Some(scripted) = self.actions.pop_front() {
+            let maybe_legal = possible_actions
+                .iter()
+                .find(|action| action.action == scripted);
+            if let Some(action) = maybe_legal {
+                return action.clone();
+            }
+            warn!("Scripted action {scripted:?} is not legal, falling back to ending the turn");
+        }
+
+        let maybe_end_turn = possible_actions
+            .iter()
+            .find(|action| matches!(action.action, SimpleAction::EndTurn));
+        if let Some(end_turn) = maybe_end_turn {
+            return end_turn.clone();
+        }
+        possible_actions
+            .first()
+            .expect("There should always be at least one playable action")
+            .clone()
+    }
+
+    fn get_deck(&self) -> Deck {
+        self.deck.clone()
+    }
+}
+
+impl Debug for ScriptedPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ScriptedPlayer")
+    }
+}
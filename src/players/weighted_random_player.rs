@@ -13,10 +13,10 @@ pub struct WeightedRandomPlayer {
 
 impl Player for WeightedRandomPlayer {
     fn decision_fn(&mut self, rng: &mut StdRng, _: &State, possible_actions: &[Action]) -> Action {
-        // Get weights for the possible actions
-        let weights: Vec<u32> = possible_actions
+        // Get weights for the possible actions, scaled by move generation's hint
+        let weights: Vec<f32> = possible_actions
             .iter()
-            .map(|action| get_weight(&action.action))
+            .map(|action| get_weight(&action.action) as f32 * action.weight)
             .collect();
 
         // Create a WeightedIndex based on the weights
@@ -52,7 +52,10 @@ fn get_weight(action: &SimpleAction) -> u32 {
         SimpleAction::Retreat(_) => 2,
         SimpleAction::EndTurn => 1,
         SimpleAction::Heal { .. } => 5,
+        SimpleAction::FullHeal { .. } => 5,
         SimpleAction::Activate { .. } => 1,
+        SimpleAction::ActivateWithEffect { .. } => 1,
+        SimpleAction::ActivateOpponent { .. } => 1,
         SimpleAction::CommunicatePokemon { .. } => 5,
         SimpleAction::ShufflePokemonIntoDeck { .. } => 5,
         SimpleAction::ShuffleOpponentSupporter { .. } => 5,
@@ -64,3 +67,35 @@ fn get_weight(action: &SimpleAction) -> u32 {
         SimpleAction::Noop => 0, // No operation has no weight
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_samples_proportionally_to_action_weight() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut player = WeightedRandomPlayer {
+            deck: Deck::default(),
+        };
+        let state = State::default();
+        let actions = vec![
+            Action {
+                actor: 0,
+                action: SimpleAction::Attack(0),
+                is_stack: false,
+                weight: 3.0,
+            },
+            Action::new(0, SimpleAction::Attack(1), false),
+        ];
+
+        let samples = 10_000;
+        let first_picked = (0..samples)
+            .filter(|_| player.decision_fn(&mut rng, &state, &actions) == actions[0])
+            .count();
+        let ratio = first_picked as f64 / samples as f64;
+        assert!((ratio - 0.75).abs() < 0.02, "ratio was {ratio}");
+    }
+}
@@ -0,0 +1,107 @@
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::{apply_action, Action},
+    State,
+};
+
+/// Recording of a game that can be re-applied deterministically, useful for debugging desyncs.
+/// `Game::apply_action` applies every action with its own RNG, seeded from the game RNG, so
+/// re-applying `actions` on `initial_state` with their `action_seeds` yields the same final
+/// State, without having to replay the player decisions that also draw from the game RNG.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub initial_state: State,
+    pub actions: Vec<Action>,
+    /// Seed of the RNG each of `actions` was applied with.
+    pub action_seeds: Vec<u64>,
+}
+
+impl Replay {
+    pub fn new(seed: u64, initial_state: State) -> Self {
+        Replay {
+            seed,
+            initial_state,
+            actions: vec![],
+            action_seeds: vec![],
+        }
+    }
+
+    pub(crate) fn record(&mut self, action: &Action, action_seed: u64) {
+        self.actions.push(action.clone());
+        self.action_seeds.push(action_seed);
+    }
+
+    /// Re-applies all recorded actions on top of the initial state.
+    pub fn reconstruct(&self) -> State {
+        let mut state = self.initial_state.clone();
+        for (action, action_seed) in self.actions.iter().zip(&self.action_seeds) {
+            apply_action(&mut StdRng::seed_from_u64(*action_seed), &mut state, action);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::EnergyType,
+        players::{Player, RandomPlayer},
+        test_helpers::load_test_decks,
+        Deck, Game,
+    };
+
+    fn random_players(deck_a: Deck, deck_b: Deck) -> Vec<Box<dyn Player>> {
+        vec![
+            Box::new(RandomPlayer { deck: deck_a }),
+            Box::new(RandomPlayer { deck: deck_b }),
+        ]
+    }
+
+    #[test]
+    fn test_recorded_game_replays_to_identical_state() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut game = Game::new(random_players(deck_a, deck_b), 42);
+        game.record_replay();
+        game.play();
+
+        let replay = game.get_replay().expect("Game should be recording");
+        assert!(!replay.actions.is_empty());
+        assert_eq!(replay.reconstruct(), game.get_state_clone());
+
+        // Also survives a serialization round-trip
+        let json = serde_json::to_string(replay).unwrap();
+        let deserialized: Replay = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.reconstruct(), game.get_state_clone());
+    }
+
+    #[test]
+    fn test_multi_energy_game_replays_to_identical_state() {
+        let (mut deck_a, mut deck_b) = load_test_decks();
+        deck_a.energy_types = vec![EnergyType::Grass, EnergyType::Fire, EnergyType::Water];
+        deck_b.energy_types = vec![EnergyType::Darkness, EnergyType::Psychic];
+        for seed in 0..5 {
+            let mut game = Game::new(random_players(deck_a.clone(), deck_b.clone()), seed);
+            game.record_replay();
+            game.play();
+
+            let replay = game.get_replay().expect("Game should be recording");
+            assert_eq!(replay.reconstruct(), game.get_state_clone());
+        }
+    }
+
+    #[test]
+    fn test_recording_does_not_change_the_game() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut recorded = Game::new(random_players(deck_a.clone(), deck_b.clone()), 7);
+        recorded.record_replay();
+        recorded.play();
+        let mut unrecorded = Game::new(random_players(deck_a, deck_b), 7);
+        unrecorded.play();
+
+        assert_eq!(recorded.get_state_clone(), unrecorded.get_state_clone());
+    }
+}
@@ -1,6 +1,7 @@
 use core::panic;
-use log::{info, warn};
+use log::{debug, info, warn};
 use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
 use std::{
     any,
     time::{Duration, Instant},
@@ -21,6 +22,8 @@ pub trait SimulationEventHandler: any::Any + Send {
 
     // Game Methods (these will be called on per-thread instances of SimulationEventHandler)
     fn on_game_start(&mut self, _game_id: Uuid) {}
+    /// Called once per turn, before its first action. The setup phase is turn 0.
+    fn on_turn_start(&mut self, _game_id: Uuid, _turn: u8, _player: usize, _state: &State) {}
     fn on_action(
         &mut self,
         _game_id: Uuid,
@@ -30,6 +33,12 @@ pub trait SimulationEventHandler: any::Any + Send {
         _action: &Action,
     ) {
     }
+    /// Called after an action is applied once no decisions are pending (i.e. the move
+    /// generation stack is empty), or when the game is over. Ticks that leave a choice to make
+    /// (e.g. promoting a new Active, picking a target) do not produce a snapshot.
+    fn on_state_snapshot(&mut self, _game_id: Uuid, _state: &State) {}
+    /// Called once per turn, after its last action (including the one that ends the game).
+    fn on_turn_end(&mut self, _game_id: Uuid, _turn: u8, _player: usize, _state: &State) {}
     fn on_game_end(&mut self, _game_id: Uuid, _state: State, _result: Option<GameOutcome>) {}
 }
 
@@ -42,6 +51,13 @@ impl CompositeSimulationEventHandler {
     pub fn new(handlers: Vec<Box<dyn SimulationEventHandler>>) -> Self {
         Self { handlers }
     }
+
+    /// The first composed handler of type `T`, e.g. to read its results after a game.
+    pub fn get_handler<T: SimulationEventHandler>(&self) -> Option<&T> {
+        self.handlers
+            .iter()
+            .find_map(|handler| (&**handler as &dyn any::Any).downcast_ref::<T>())
+    }
 }
 
 impl SimulationEventHandler for CompositeSimulationEventHandler {
@@ -51,6 +67,12 @@ impl SimulationEventHandler for CompositeSimulationEventHandler {
         }
     }
 
+    fn on_turn_start(&mut self, game_id: Uuid, turn: u8, player: usize, state: &State) {
+        for handler in self.handlers.iter_mut() {
+            handler.on_turn_start(game_id, turn, player, state);
+        }
+    }
+
     fn on_action(
         &mut self,
         game_id: Uuid,
@@ -70,6 +92,18 @@ impl SimulationEventHandler for CompositeSimulationEventHandler {
         }
     }
 
+    fn on_state_snapshot(&mut self, game_id: Uuid, state: &State) {
+        for handler in self.handlers.iter_mut() {
+            handler.on_state_snapshot(game_id, state);
+        }
+    }
+
+    fn on_turn_end(&mut self, game_id: Uuid, turn: u8, player: usize, state: &State) {
+        for handler in self.handlers.iter_mut() {
+            handler.on_turn_end(game_id, turn, player, state);
+        }
+    }
+
     fn on_game_end(&mut self, game_id: Uuid, state: State, result: Option<GameOutcome>) {
         for handler in self.handlers.iter_mut() {
             handler.on_game_end(game_id, state.clone(), result);
@@ -248,3 +282,106 @@ impl SimulationEventHandler for StatsCollector {
         );
     }
 }
+
+/// One action of a game, as written by JsonLogHandler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggedAction {
+    pub turn: u8,
+    pub action: Action,
+}
+
+/// The complete log of a game, as written by JsonLogHandler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameLog {
+    pub game_id: String,
+    pub outcome: Option<GameOutcome>,
+    pub points: [u8; 2],
+    pub turn_count: u8,
+    pub actions: Vec<LoggedAction>,
+}
+
+/// Accumulates every action of a game and emits the whole game as a single JSON
+/// document (a serialized GameLog) when it ends, for offline analysis.
+#[derive(Default)]
+pub struct JsonLogHandler {
+    current_actions: Vec<LoggedAction>,
+    logs: Vec<String>,
+}
+
+impl JsonLogHandler {
+    /// JSON documents of all finished games, in the order they were merged.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+}
+
+impl SimulationEventHandler for JsonLogHandler {
+    fn on_game_start(&mut self, _game_id: Uuid) {
+        self.current_actions.clear();
+    }
+
+    fn on_action(
+        &mut self,
+        _game_id: Uuid,
+        state_before_action: &State,
+        _actor: usize,
+        _playable_actions: &[Action],
+        action: &Action,
+    ) {
+        self.current_actions.push(LoggedAction {
+            turn: state_before_action.turn_count,
+            action: action.clone(),
+        });
+    }
+
+    fn on_game_end(&mut self, game_id: Uuid, state: State, outcome: Option<GameOutcome>) {
+        let log = GameLog {
+            game_id: game_id.to_string(),
+            outcome,
+            points: state.points,
+            turn_count: state.turn_count,
+            actions: std::mem::take(&mut self.current_actions),
+        };
+        let json = serde_json::to_string(&log).expect("GameLog should always be serializable");
+        debug!("Game {game_id} log: {json}");
+        self.logs.push(json);
+    }
+
+    fn merge(&mut self, other: &dyn SimulationEventHandler) {
+        if let Some(other_mytype) = (other as &dyn any::Any).downcast_ref::<JsonLogHandler>() {
+            self.logs.extend(other_mytype.logs.iter().cloned());
+        } else {
+            panic!("Attempted to merge JsonLogHandler with incompatible type");
+        }
+    }
+}
+
+/// Keeps a copy of every settled State (see `on_state_snapshot`), so that a viewer can scrub
+/// through a game.
+/// Meant for watching single games: merging keeps the snapshots of every game.
+#[derive(Default)]
+pub struct SnapshotRecorder {
+    snapshots: Vec<State>,
+}
+
+impl SnapshotRecorder {
+    /// Settled states, in the order they were played (and merged).
+    pub fn snapshots(&self) -> &[State] {
+        &self.snapshots
+    }
+}
+
+impl SimulationEventHandler for SnapshotRecorder {
+    fn on_state_snapshot(&mut self, _game_id: Uuid, state: &State) {
+        self.snapshots.push(state.clone());
+    }
+
+    fn merge(&mut self, other: &dyn SimulationEventHandler) {
+        if let Some(other_mytype) = (other as &dyn any::Any).downcast_ref::<SnapshotRecorder>() {
+            self.snapshots
+                .extend(other_mytype.snapshots.iter().cloned());
+        } else {
+            panic!("Attempted to merge SnapshotRecorder with incompatible type");
+        }
+    }
+}
@@ -1,27 +1,45 @@
 use log::{debug, trace};
-use rand::{seq::SliceRandom, Rng};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::hash::Hash;
 
 use crate::{
-    actions::SimpleAction,
+    actions::{apply_action, PendingChoice, SimpleAction},
+    card_logic::is_rare_candy,
     deck::Deck,
     effects::TurnEffect,
+    hooks::{can_evolve_into, energy_missing, get_attack_cost},
     models::{Card, EnergyType, PlayedCard},
+    move_generation::{generate_possible_actions, generate_possible_trainer_actions},
+    players::Player,
 };
 
+const MAX_MULLIGANS: usize = 10;
+const POINTS_TO_WIN: u8 = 3;
+const DECK_SIZE: usize = 20;
+const MAX_COPIES_PER_NAME: usize = 2;
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum GameOutcome {
     Win(usize),
     Tie,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct State {
     // Turn State
     pub winner: Option<GameOutcome>,
     pub points: [u8; 2],
+    // Points needed to win the game. Set it after creating the State to simulate other formats.
+    #[serde(default = "default_points_to_win")]
+    pub points_to_win: u8,
+    // Whether the player going first gets an Energy on their opening turn. Off in Pocket.
+    #[serde(default)]
+    pub first_turn_energy: bool,
     pub turn_count: u8, // Global turn count. Matches TCGPocket app.
     // Player that needs to select from playable actions. Might not be aligned
     // with coin toss and the parity, see Sabrina.
@@ -44,11 +62,88 @@ pub struct State {
     turn_effects: BTreeMap<u8, Vec<TurnEffect>>,
 }
 
+/// A broken game-rule invariant found by `State::is_legal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateError {
+    /// Has Benched Pokémon but no Active, and isn't in the middle of promoting one.
+    MissingActive { player: usize },
+    /// Cards across hand, deck, discard pile and play don't add up to a full deck.
+    WrongCardCount { player: usize, count: usize },
+    /// More copies of a card name than a deck may hold.
+    TooManyCopies {
+        player: usize,
+        name: String,
+        count: usize,
+    },
+    /// A Pokémon has more remaining HP than its total HP.
+    HpAboveTotal { player: usize, in_play_idx: usize },
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::MissingActive { player } => {
+                write!(f, "Player {player} has a Bench but no Active Pokemon")
+            }
+            StateError::WrongCardCount { player, count } => {
+                write!(f, "Player {player} has {count} cards, expected {DECK_SIZE}")
+            }
+            StateError::TooManyCopies {
+                player,
+                name,
+                count,
+            } => write!(f, "Player {player} has {count} copies of {name}"),
+            StateError::HpAboveTotal {
+                player,
+                in_play_idx,
+            } => write!(
+                f,
+                "Player {player}'s Pokemon at {in_play_idx} has more remaining HP than total HP"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// What a given player is allowed to see of a State. Used to ship the game to clients without
+/// leaking the opponent's hand or the order/contents of any deck.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicState {
+    pub player: usize, // Perspective of this view
+    pub winner: Option<GameOutcome>,
+    pub points: [u8; 2],
+    pub turn_count: u8,
+    pub current_player: usize,
+    pub current_energy: Option<EnergyType>,
+    pub hand: Vec<Card>, // Only the viewing player's hand
+    pub hand_sizes: [usize; 2],
+    pub deck_sizes: [usize; 2],
+    pub discard_piles: [Vec<Card>; 2],
+    pub discard_energies: [Vec<EnergyType>; 2],
+    pub in_play_pokemon: [[Option<PlayedCard>; 4]; 2],
+}
+
+/// The public information a player observes when making a decision.
+pub type Observation = PublicState;
+
+fn default_points_to_win() -> u8 {
+    POINTS_TO_WIN
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::new(&Deck::default(), &Deck::default())
+    }
+}
+
 impl State {
     pub fn new(deck_a: &Deck, deck_b: &Deck) -> Self {
         Self {
             winner: None,
             points: [0, 0],
+            points_to_win: POINTS_TO_WIN,
+            first_turn_energy: false,
             turn_count: 0,
             current_player: 0,
             move_generation_stack: Vec::new(),
@@ -77,6 +172,142 @@ impl State {
         )
     }
 
+    /// Returns the information `player` is allowed to see: their own hand, both boards,
+    /// points and discard piles, but only the sizes of the opponent's hand and of both decks.
+    pub fn public_view_for(&self, player: usize) -> PublicState {
+        PublicState {
+            player,
+            winner: self.winner,
+            points: self.points,
+            turn_count: self.turn_count,
+            current_player: self.current_player,
+            current_energy: self.current_energy,
+            hand: self.hands[player].clone(),
+            hand_sizes: [self.hands[0].len(), self.hands[1].len()],
+            deck_sizes: [self.decks[0].cards.len(), self.decks[1].cards.len()],
+            discard_piles: self.discard_piles.clone(),
+            discard_energies: self.discard_energies.clone(),
+            in_play_pokemon: self.in_play_pokemon.clone(),
+        }
+    }
+
+    /// What `player` observes of the game. Pocket has no face-down prizes, so redacting the
+    /// opponent's hand and the contents of both decks leaves only public information.
+    pub fn observe(&self, player: usize) -> Observation {
+        self.public_view_for(player)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("State should always be serializable")
+    }
+
+    /// Deserializes a State (e.g. one shipped across the WASM/FFI boundary) and checks
+    /// it is internally consistent before handing it to the engine.
+    pub fn from_json(json: &str) -> Result<State, serde_json::Error> {
+        let state: State = serde_json::from_str(json)?;
+        state.validate().map_err(serde::de::Error::custom)?;
+        Ok(state)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.current_player > 1 {
+            return Err(format!("Invalid current_player {}", self.current_player));
+        }
+        if let Some((actor, _)) = self
+            .move_generation_stack
+            .iter()
+            .find(|(actor, _)| *actor > 1)
+        {
+            return Err(format!("Invalid actor {actor} in move_generation_stack"));
+        }
+        for player in 0..2 {
+            for (i, pokemon) in self.enumerate_in_play_pokemon(player) {
+                if pokemon.total_hp == 0 || pokemon.remaining_hp > pokemon.total_hp {
+                    return Err(format!(
+                        "Player {player}'s Pokemon at {i} has invalid HP {}/{}",
+                        pokemon.remaining_hp, pokemon.total_hp
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks game-rule invariants that a hand-built State (e.g. in tests) can easily break.
+    /// Stricter than what `from_json` requires: every error found is returned.
+    pub fn is_legal(&self) -> Result<(), Vec<StateError>> {
+        let mut errors = vec![];
+        for player in 0..2 {
+            let has_bench = self.enumerate_bench_pokemon(player).next().is_some();
+            let is_promoting = self
+                .move_generation_stack
+                .iter()
+                .any(|(actor, _)| *actor == player);
+            if self.turn_count > 0
+                && self.winner.is_none()
+                && self.in_play_pokemon[player][0].is_none()
+                && has_bench
+                && !is_promoting
+            {
+                errors.push(StateError::MissingActive { player });
+            }
+
+            let mut names: Vec<String> = self.hands[player]
+                .iter()
+                .chain(&self.decks[player].cards)
+                .chain(&self.discard_piles[player])
+                .map(|card| card.get_name())
+                .collect();
+            for (in_play_idx, pokemon) in self.enumerate_in_play_pokemon(player) {
+                names.push(pokemon.get_name());
+                names.extend(pokemon.cards_behind.iter().map(|card| card.get_name()));
+                names.extend(
+                    pokemon
+                        .attached_tools
+                        .iter()
+                        .map(|tool| tool.to_card().get_name()),
+                );
+                if pokemon.remaining_hp > pokemon.total_hp {
+                    errors.push(StateError::HpAboveTotal {
+                        player,
+                        in_play_idx,
+                    });
+                }
+            }
+            if names.len() != DECK_SIZE {
+                errors.push(StateError::WrongCardCount {
+                    player,
+                    count: names.len(),
+                });
+            }
+
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for name in names {
+                *counts.entry(name).or_insert(0) += 1;
+            }
+            let mut over_limit: Vec<_> = counts
+                .into_iter()
+                .filter(|(_, count)| *count > MAX_COPIES_PER_NAME)
+                .collect();
+            over_limit.sort();
+            errors.extend(
+                over_limit
+                    .into_iter()
+                    .map(|(name, count)| StateError::TooManyCopies {
+                        player,
+                        name,
+                        count,
+                    }),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     pub fn initialize(deck_a: &Deck, deck_b: &Deck, rng: &mut impl Rng) -> Self {
         let mut state = Self::new(deck_a, deck_b);
 
@@ -89,12 +320,48 @@ impl State {
             state.maybe_draw_card(0);
             state.maybe_draw_card(1);
         }
+        for player in 0..2 {
+            state.resolve_mulligan(player, rng);
+        }
         // Flip a coin to determine the starting player
         state.current_player = rng.gen_range(0..2);
 
         state
     }
 
+    /// Returns true if the player is still in the setup phase without an Active Pokemon
+    /// and has no Basic Pokemon in hand to put there (i.e. the opening hand must be redrawn).
+    pub fn needs_mulligan(&self, player: usize) -> bool {
+        self.turn_count == 0
+            && self.in_play_pokemon[player][0].is_none()
+            && !self.hands[player].iter().any(|card| card.is_basic())
+    }
+
+    /// Shuffles the opening hand back into the deck and redraws 5 cards until it contains
+    /// a Basic Pokemon. Capped at MAX_MULLIGANS attempts to never loop forever.
+    fn resolve_mulligan(&mut self, player: usize, rng: &mut impl Rng) {
+        let mut attempts = 0;
+        while self.needs_mulligan(player) && attempts < MAX_MULLIGANS {
+            debug!(
+                "Player {} has no Basic Pokemon in hand, redrawing",
+                player + 1
+            );
+            self.decks[player].cards.append(&mut self.hands[player]);
+            self.decks[player].shuffle(true, rng);
+            for _ in 0..5 {
+                self.maybe_draw_card(player);
+            }
+            attempts += 1;
+        }
+    }
+
+    /// Damage taken by the Pokemon in the given in-play slot (0 if the slot is empty).
+    pub fn damage_on(&self, player: usize, slot: usize) -> u32 {
+        self.in_play_pokemon[player][slot]
+            .as_ref()
+            .map_or(0, |pokemon| pokemon.total_hp - pokemon.remaining_hp)
+    }
+
     pub fn get_remaining_hp(&self, player: usize, index: usize) -> u32 {
         self.in_play_pokemon[player][index]
             .as_ref()
@@ -120,6 +387,7 @@ impl State {
         self.hands[player].iter().filter(|card| card.is_support())
     }
 
+    /// Pocket has no deck-out loss: drawing from an empty deck just doesn't draw.
     pub(crate) fn maybe_draw_card(&mut self, player: usize) {
         if let Some(card) = self.decks[player].draw() {
             self.hands[player].push(card.clone());
@@ -135,6 +403,63 @@ impl State {
         }
     }
 
+    /// Draws up to `amount` cards, stopping early if the deck runs out (which is not a loss
+    /// in Pocket). Returns how many cards were actually drawn.
+    pub(crate) fn draw_cards(&mut self, player: usize, amount: usize) -> usize {
+        let available = amount.min(self.decks[player].cards.len());
+        for _ in 0..available {
+            self.maybe_draw_card(player);
+        }
+        if available < amount {
+            debug!(
+                "Player {} could only draw {available} of {amount} cards",
+                player + 1
+            );
+        }
+        available
+    }
+
+    /// Discards the cards at the given hand positions, keeping the rest of the hand in order.
+    pub fn discard_from_hand(&mut self, player: usize, indices: &[usize]) {
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), indices.len(), "Hand indices should be unique");
+        let discarded: Vec<Card> = sorted
+            .iter()
+            .rev()
+            .map(|&i| self.hands[player].remove(i))
+            .collect();
+        self.discard_piles[player].extend(discarded.into_iter().rev());
+    }
+
+    /// Moves up to `max_count` deck cards matching `predicate` to `player`'s hand, then
+    /// shuffles their deck. Matches are taken in deck order; since the deck is kept shuffled,
+    /// that is as good as a random pick. Returns how many cards were moved.
+    pub(crate) fn search_deck_to_hand(
+        &mut self,
+        player: usize,
+        mut predicate: impl FnMut(&Card) -> bool,
+        max_count: usize,
+        rng: &mut impl Rng,
+    ) -> usize {
+        let mut found = vec![];
+        let mut i = 0;
+        let deck = &mut self.decks[player].cards;
+        while found.len() < max_count && i < deck.len() {
+            if predicate(&deck[i]) {
+                found.push(deck.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+        debug!("Player {player} searched their deck for {found:?}");
+        let moved = found.len();
+        self.hands[player].extend(found);
+        self.decks[player].shuffle(false, rng);
+        moved
+    }
+
     pub(crate) fn transfer_card_from_deck_to_hand(&mut self, player: usize, card: &Card) {
         // Remove from deck and add to hand
         let pos = self.decks[player]
@@ -169,15 +494,62 @@ impl State {
             .filter(|card| matches!(card, Card::Pokemon(_)))
     }
 
-    pub(crate) fn generate_energy(&mut self) {
+    /// All the ways `player` can evolve a Pokémon: the normal Evolve actions, plus playing a
+    /// Rare Candy from hand when it would enable a Stage 2 evolution (deduplicated). Rare Candy
+    /// goes through its usual Play action, so it is consumed and respects item lock.
+    pub fn evolution_actions(&self, player: usize) -> Vec<SimpleAction> {
+        if self.is_users_first_turn() {
+            return vec![];
+        }
+
+        let mut actions = vec![];
+        for (in_play_idx, pokemon) in self.enumerate_in_play_pokemon(player) {
+            if pokemon.played_this_turn {
+                continue;
+            }
+            for card in self.iter_hand_pokemon(player) {
+                let action = SimpleAction::Evolve(card.clone(), in_play_idx);
+                if can_evolve_into(card, pokemon) && !actions.contains(&action) {
+                    actions.push(action);
+                }
+            }
+        }
+
+        // Trainer playability is only computed for the current player
+        if player == self.current_player {
+            for card in self.hands[player].iter().filter(|card| is_rare_candy(card)) {
+                let trainer_card = card.as_trainer();
+                let playable = generate_possible_trainer_actions(self, &trainer_card)
+                    .is_some_and(|actions| !actions.is_empty());
+                let action = SimpleAction::Play { trainer_card };
+                if playable && !actions.contains(&action) {
+                    actions.push(action);
+                }
+            }
+        }
+        actions
+    }
+
+    /// Whether `player` gets an Energy in their Energy Zone this turn: only the turn player does,
+    /// and the player going first skips their opening turn unless `first_turn_energy` is set.
+    pub fn generates_energy_this_turn(&self, player: usize) -> bool {
+        if self.turn_count == 0 || player != self.turn_player() {
+            return false;
+        }
+        self.turn_count > 1 || self.first_turn_energy
+    }
+
+    /// Picks the current player's Energy Zone energy, with the game RNG so that games stay
+    /// reproducible from their seed.
+    pub(crate) fn generate_energy(&mut self, rng: &mut impl Rng) {
         if self.decks[self.current_player].energy_types.len() == 1 {
             self.current_energy = Some(self.decks[self.current_player].energy_types[0]);
+            return;
         }
 
         let deck_energies = &self.decks[self.current_player].energy_types;
-        let mut rng = rand::thread_rng();
         let generated = deck_energies
-            .choose(&mut rng)
+            .choose(rng)
             .expect("Decks should have at least 1 energy");
         self.current_energy = Some(*generated);
     }
@@ -240,6 +612,15 @@ impl State {
             .map(|(i, x)| (i, x.as_ref().unwrap()))
     }
 
+    /// In-play Pokemon (Active and Bench) that have damage on them.
+    pub fn enumerate_damaged_pokemon(
+        &self,
+        player: usize,
+    ) -> impl Iterator<Item = (usize, &PlayedCard)> {
+        self.enumerate_in_play_pokemon(player)
+            .filter(|(_, x)| x.is_damaged())
+    }
+
     // e.g. returns (1, Weezing) if player 1 has Weezing in 1st bench slot
     pub fn enumerate_bench_pokemon(
         &self,
@@ -254,6 +635,48 @@ impl State {
             .push((actor, vec![SimpleAction::DrawCard { amount }]));
     }
 
+    /// How many more K.O.s `player` needs to win, in the best case of knocking out the
+    /// opponent's most valuable Pokemon in play first (ex count double). K.O.s beyond what
+    /// is currently in play are assumed to be worth 1 point.
+    pub fn kos_remaining(&self, player: usize) -> u8 {
+        let opponent = Self::opponent_of(player);
+        let mut knockout_points: Vec<u8> = self
+            .enumerate_in_play_pokemon(opponent)
+            .map(|(_, pokemon)| pokemon.card.get_knockout_points())
+            .collect();
+        knockout_points.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut points_needed = self.points_to_win.saturating_sub(self.points[player]);
+        let mut kos = 0;
+        let mut knockout_points = knockout_points.into_iter();
+        while points_needed > 0 {
+            points_needed = points_needed.saturating_sub(knockout_points.next().unwrap_or(1));
+            kos += 1;
+        }
+        kos
+    }
+
+    /// True if all 3 Bench slots are taken.
+    pub fn is_bench_full(&self, player: usize) -> bool {
+        self.available_bench_slots(player) == 0
+    }
+
+    /// Number of empty Bench slots (out of 3) for `player`.
+    pub fn available_bench_slots(&self, player: usize) -> usize {
+        self.in_play_pokemon[player][1..]
+            .iter()
+            .filter(|x| x.is_none())
+            .count()
+    }
+
+    /// Index (1..=3) of the leftmost empty Bench slot, or None if the Bench is full.
+    pub fn first_open_bench_slot(&self, player: usize) -> Option<usize> {
+        self.in_play_pokemon[player][1..]
+            .iter()
+            .position(|x| x.is_none())
+            .map(|i| i + 1)
+    }
+
     pub fn maybe_get_active(&self, player: usize) -> Option<&PlayedCard> {
         self.in_play_pokemon[player][0].as_ref()
     }
@@ -271,29 +694,103 @@ impl State {
     }
 
     // This function should be called only from turn 1 onwards
-    pub(crate) fn advance_turn(&mut self) {
+    pub(crate) fn advance_turn(&mut self, rng: &mut impl Rng) {
         debug!(
             "Ending turn moving from player {} to player {}",
             self.current_player,
-            (self.current_player + 1) % 2
+            self.opponent()
         );
-        self.current_player = (self.current_player + 1) % 2;
+        self.current_player = self.opponent();
         self.turn_count += 1;
         self.end_turn_maintenance();
         self.queue_draw_action(self.current_player, 1);
-        self.generate_energy();
+        if self.generates_energy_this_turn(self.current_player) {
+            self.generate_energy(rng);
+        }
     }
 
     pub(crate) fn is_game_over(&self) -> bool {
         self.winner.is_some() || self.turn_count >= 100
     }
 
+    /// Plays out the current turn on a clone of this state, letting `player` make every
+    /// decision (including any the opponent is prompted for mid-turn), until the turn
+    /// passes or the game ends. Returns the resulting state.
+    pub fn simulate_turn(&self, player: &mut dyn Player, rng: &mut StdRng) -> State {
+        let mut state = self.clone();
+        let starting_turn = state.turn_count;
+        while !state.is_game_over() && state.turn_count == starting_turn {
+            let (_, actions) = generate_possible_actions(&state);
+            let action = if actions.len() == 1 {
+                actions[0].clone()
+            } else {
+                player.decision_fn(rng, &state, &actions)
+            };
+            apply_action(rng, &mut state, &action);
+        }
+        state
+    }
+
+    /// Total number of energies attached to the player's Pokemon in play. If `with_jungle_totem`
+    /// is true, Grass energies count double on Grass Pokemon when Jungle Totem is active.
+    pub fn total_energy_attached(&self, player: usize, with_jungle_totem: bool) -> usize {
+        self.enumerate_in_play_pokemon(player)
+            .map(|(_, pokemon)| {
+                if with_jungle_totem {
+                    pokemon.effective_energy_count(self, player)
+                } else {
+                    pokemon.attached_energy.len()
+                }
+            })
+            .sum()
+    }
+
+    /// Of the Active Pokemon's attacks that can't be paid for yet, returns the one needing the
+    /// fewest additional energies as (attack_index, energies_needed). Ties go to the first
+    /// attack. None if there is no Active Pokemon or every attack is already affordable.
+    pub fn cheapest_unlocked_attack(&self, player: usize) -> Option<(usize, usize)> {
+        let active = self.maybe_get_active(player)?;
+        active
+            .get_attacks()
+            .iter()
+            .enumerate()
+            .map(|(i, attack)| {
+                let cost = get_attack_cost(&attack.energy_required, self, player);
+                (i, energy_missing(active, &cost, self, player).len())
+            })
+            .filter(|(_, missing)| *missing > 0)
+            .min_by_key(|(_, missing)| *missing)
+    }
+
     pub(crate) fn num_in_play_of_type(&self, player: usize, energy: EnergyType) -> usize {
         self.enumerate_in_play_pokemon(player)
             .filter(|(_, x)| x.get_energy_type() == Some(energy))
             .count()
     }
 
+    /// The player whose turn it is, even while the opponent resolves a forced decision.
+    pub fn turn_player(&self) -> usize {
+        self.current_player
+    }
+
+    /// The opponent of the turn player.
+    pub fn opponent(&self) -> usize {
+        Self::opponent_of(self.current_player)
+    }
+
+    /// The other player of a two-player game.
+    pub fn opponent_of(player: usize) -> usize {
+        (player + 1) % 2
+    }
+
+    /// The player who has to pick the next action: whoever owns the pending decision on top of
+    /// the `move_generation_stack`, or the turn player if nothing is pending.
+    pub fn next_actor(&self) -> usize {
+        self.move_generation_stack
+            .last()
+            .map_or(self.current_player, |(actor, _)| *actor)
+    }
+
     pub(crate) fn is_users_first_turn(&self) -> bool {
         self.turn_count <= 2
     }
@@ -323,31 +820,105 @@ impl State {
             .extend(energies.iter().cloned());
     }
 
-    /// Discards a Pokemon from play, moving it, its evolution chain, and its energies
-    ///  to the discard pile.
+    /// Moves a single energy of the given type from the discard pile to a Pokemon in play.
+    /// Returns false (leaving the state untouched) if there is no such energy in the discard
+    /// pile or no Pokemon at `in_play_idx`.
+    pub fn attach_energy_from_discard(
+        &mut self,
+        player: usize,
+        in_play_idx: usize,
+        energy: EnergyType,
+    ) -> bool {
+        let has_energy = self.discard_energies[player].contains(&energy);
+        let has_pokemon = self.in_play_pokemon[player][in_play_idx].is_some();
+        if !has_energy || !has_pokemon {
+            return false;
+        }
+        self.attach_energies_from_discard(player, in_play_idx, &[energy]);
+        true
+    }
+
+    /// Queues `choice` for `player` by pushing its concrete actions onto the
+    /// `move_generation_stack`. Choices with no available options are dropped.
+    pub fn push_pending_choice(&mut self, player: usize, choice: PendingChoice) {
+        let actions = choice.to_actions(self, player);
+        if actions.is_empty() {
+            return;
+        }
+        self.move_generation_stack.push((player, actions));
+    }
+
+    /// Discards a Pokemon from play, moving it, its evolution chain, its attached Tools and
+    ///  its energies to the discard pile.
     pub(crate) fn discard_from_play(&mut self, ko_receiver: usize, ko_pokemon_idx: usize) {
         let ko_pokemon = self.in_play_pokemon[ko_receiver][ko_pokemon_idx]
             .as_ref()
             .expect("There should be a Pokemon to discard");
         let mut cards_to_discard = ko_pokemon.cards_behind.clone();
-        // TODO: Include attached Tools
         cards_to_discard.push(ko_pokemon.card.clone());
+        cards_to_discard.extend(ko_pokemon.attached_tools.iter().map(|tool| tool.to_card()));
         debug!("Discarding: {cards_to_discard:?}");
         self.discard_piles[ko_receiver].extend(cards_to_discard);
         self.discard_energies[ko_receiver].extend(ko_pokemon.attached_energy.iter().cloned());
         self.in_play_pokemon[ko_receiver][ko_pokemon_idx] = None;
     }
 
+    /// Puts `player`'s Active Pokemon, its evolution chain, and any attached Tool back into
+    /// their deck and shuffles it. Attached energies go to the discard pile.
+    pub(crate) fn return_active_to_deck(&mut self, player: usize, rng: &mut impl Rng) {
+        let active = self.in_play_pokemon[player][0]
+            .take()
+            .expect("There should be an Active Pokemon to return");
+        debug!("Returning {active:?} to deck");
+        let deck = &mut self.decks[player];
+        deck.cards.extend(active.cards_behind);
+        deck.cards.push(active.card);
+        deck.cards
+            .extend(active.attached_tools.iter().map(|tool| tool.to_card()));
+        deck.shuffle(false, rng);
+        self.discard_energies[player].extend(active.attached_energy);
+    }
+
+    /// Puts `player`'s Pokemon at `in_play_idx`, its evolution chain, and any attached Tool into
+    /// their hand. Attached energies go to the discard pile, and effects on it are lost. If it
+    /// was the Active Pokemon, `player` has to promote a new one.
+    pub(crate) fn return_to_hand(&mut self, player: usize, in_play_idx: usize) {
+        let pokemon = self.in_play_pokemon[player][in_play_idx]
+            .take()
+            .expect("There should be a Pokemon to return to hand");
+        debug!("Returning {pokemon:?} to hand");
+        let hand = &mut self.hands[player];
+        hand.extend(pokemon.cards_behind);
+        hand.push(pokemon.card);
+        hand.extend(pokemon.attached_tools.iter().map(|tool| tool.to_card()));
+        // Energy can't go to hand, so it goes to the discard pile
+        self.discard_energies[player].extend(pokemon.attached_energy);
+        if in_play_idx == 0 {
+            self.push_pending_choice(player, PendingChoice::PromoteActive);
+        }
+    }
+
+    /// Heals `amount` damage from each of `player`'s Pokémon in play (each capped at its max HP).
+    pub(crate) fn heal_all(&mut self, player: usize, amount: u32) {
+        for pokemon in self.in_play_pokemon[player].iter_mut().flatten() {
+            pokemon.heal(amount);
+        }
+    }
+
     pub(crate) fn discard_from_active(&mut self, actor: usize, to_discard: &[EnergyType]) {
-        self.discard_energies[actor].extend(to_discard.iter().cloned());
+        // With Jungle Totem a [G] Energy counts twice when paying for the attack, but only
+        // the physically attached ones can be discarded.
+        let double_grass = self.get_active(actor).has_double_grass(self, actor);
         let active = self.get_active_mut(actor);
+        let mut discarded = vec![];
         for energy in to_discard {
             if let Some(pos) = active.attached_energy.iter().position(|x| x == energy) {
-                active.attached_energy.swap_remove(pos);
-            } else {
+                discarded.push(active.attached_energy.swap_remove(pos));
+            } else if !(double_grass && *energy == EnergyType::Grass) {
                 panic!("Active Pokemon does not have energy to discard");
             }
         }
+        self.discard_energies[actor].extend(discarded);
     }
 }
 
@@ -380,9 +951,18 @@ fn to_canonical_names(cards: &[Card]) -> Vec<&String> {
 
 #[cfg(test)]
 mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
     use crate::{
-        card_ids::CardId, database::get_card_by_enum, deck::is_basic, hooks::to_playable_card,
+        actions::Action,
+        card_ids::CardId,
+        database::get_card_by_enum,
+        deck::is_basic,
+        effects::CardEffect,
+        hooks::{on_attach_tool, to_playable_card},
+        players::RandomPlayer,
         test_helpers::load_test_decks,
+        tool_ids::ToolId,
     };
 
     use super::*;
@@ -401,6 +981,203 @@ mod tests {
         assert_eq!(state.hands[0].len(), 1);
     }
 
+    #[test]
+    fn test_draw_cards_stops_at_empty_deck() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.decks[0].cards.truncate(3);
+
+        assert_eq!(state.draw_cards(0, 2), 2);
+        assert_eq!(state.hands[0].len(), 2);
+        assert_eq!(state.draw_cards(0, 2), 1);
+        assert_eq!(state.hands[0].len(), 3);
+        assert_eq!(state.draw_cards(0, 2), 0);
+        assert_eq!(state.hands[0].len(), 3);
+        assert!(state.decks[0].cards.is_empty());
+        assert_eq!(state.winner, None);
+    }
+
+    #[test]
+    fn test_discard_from_hand_with_multiple_indices() {
+        let mut state = State::default();
+        let hand: Vec<Card> = [
+            CardId::A1001Bulbasaur,
+            CardId::A1033Charmander,
+            CardId::A1053Squirtle,
+            CardId::PA001Potion,
+        ]
+        .into_iter()
+        .map(get_card_by_enum)
+        .collect();
+        state.hands[0] = hand.clone();
+
+        state.discard_from_hand(0, &[3, 0, 2]);
+
+        assert_eq!(state.hands[0], vec![hand[1].clone()]);
+        assert_eq!(
+            state.discard_piles[0],
+            vec![hand[0].clone(), hand[2].clone(), hand[3].clone()]
+        );
+    }
+
+    #[test]
+    fn test_discard_from_active_with_jungle_totem_only_discards_attached() {
+        let mut state = State::default();
+        let mut bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        bulbasaur.attached_energy = vec![EnergyType::Grass, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(bulbasaur);
+        state.in_play_pokemon[0][1] = Some(to_playable_card(
+            &get_card_by_enum(CardId::A1a006Serperior),
+            false,
+        ));
+
+        // The single [G] Energy paid for both [G] of the cost
+        state.discard_from_active(0, &[EnergyType::Grass, EnergyType::Grass]);
+
+        assert_eq!(state.get_active(0).attached_energy, vec![EnergyType::Water]);
+        assert_eq!(state.discard_energies[0], vec![EnergyType::Grass]);
+    }
+
+    #[test]
+    fn test_heal_all_caps_each_pokemon_at_its_max_hp() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut badly_damaged = to_playable_card(&bulbasaur, false);
+        badly_damaged.apply_damage(50);
+        let mut slightly_damaged = to_playable_card(&bulbasaur, false);
+        slightly_damaged.apply_damage(10);
+        state.in_play_pokemon[0] = [
+            Some(badly_damaged),
+            None,
+            Some(slightly_damaged),
+            Some(to_playable_card(&bulbasaur, false)),
+        ];
+        // Giant Cape: 90 max HP, 40 damage
+        state.in_play_pokemon[0][1] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][1].as_mut().unwrap().attached_tools = vec![ToolId::A2147GiantCape];
+        on_attach_tool(&mut state, 0, 1, ToolId::A2147GiantCape);
+        state.in_play_pokemon[0][1]
+            .as_mut()
+            .unwrap()
+            .apply_damage(40);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[1][0]
+            .as_mut()
+            .unwrap()
+            .apply_damage(30);
+
+        state.heal_all(0, 30);
+
+        assert_eq!(state.get_remaining_hp(0, 0), 50);
+        assert_eq!(state.get_remaining_hp(0, 1), 80);
+        assert_eq!(state.get_remaining_hp(0, 2), 70);
+        assert_eq!(state.get_remaining_hp(0, 3), 70);
+        // Opponent's Pokémon are untouched
+        assert_eq!(state.get_remaining_hp(1, 0), 40);
+    }
+
+    #[test]
+    fn test_opponent() {
+        let mut state = State::default();
+        assert_eq!(state.opponent(), 1);
+        state.current_player = 1;
+        assert_eq!(state.opponent(), 0);
+        assert_eq!(State::opponent_of(0), 1);
+        assert_eq!(State::opponent_of(1), 0);
+    }
+
+    #[test]
+    fn test_search_deck_to_hand_moves_matching_cards() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let deck_size = state.decks[0].cards.len();
+        let num_basics = state.decks[0].cards.iter().filter(|x| x.is_basic()).count();
+        assert!(num_basics >= 2);
+
+        let moved = state.search_deck_to_hand(0, Card::is_basic, 2, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(moved, 2);
+        assert_eq!(state.hands[0].len(), 2);
+        assert!(state.hands[0].iter().all(Card::is_basic));
+        assert_eq!(state.decks[0].cards.len(), deck_size - 2);
+    }
+
+    #[test]
+    fn test_search_deck_to_hand_caps_at_available_matches() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let num_basics = state.decks[0].cards.iter().filter(|x| x.is_basic()).count();
+
+        let moved = state.search_deck_to_hand(0, Card::is_basic, 20, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(moved, num_basics);
+        assert!(!state.decks[0].cards.iter().any(Card::is_basic));
+        assert_eq!(
+            state.search_deck_to_hand(0, Card::is_basic, 1, &mut StdRng::seed_from_u64(0)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_search_deck_to_hand_reshuffle_is_seeded() {
+        let (deck_a, deck_b) = load_test_decks();
+        let search = |seed: u64| {
+            let mut state = State::new(&deck_a, &deck_b);
+            state.search_deck_to_hand(0, Card::is_basic, 1, &mut StdRng::seed_from_u64(seed));
+            state.decks[0].cards.clone()
+        };
+
+        assert_eq!(search(7), search(7));
+        assert_ne!(search(7), search(8));
+    }
+
+    #[test]
+    fn test_points_to_win_defaults_to_three() {
+        assert_eq!(State::default().points_to_win, 3);
+
+        // Snapshots from before the field existed still load with the default
+        let mut json: serde_json::Value =
+            serde_json::from_str(&State::default().to_json()).unwrap();
+        json.as_object_mut().unwrap().remove("points_to_win");
+        let state: State = serde_json::from_value(json).unwrap();
+        assert_eq!(state.points_to_win, 3);
+    }
+
+    #[test]
+    fn test_return_to_hand_moves_cards_and_discards_energy() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        let mut benched = to_playable_card(&ivysaur, false);
+        benched.cards_behind = vec![bulbasaur.clone()];
+        benched.attached_energy = vec![EnergyType::Grass];
+        benched.attached_tools = vec![ToolId::A2147GiantCape];
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.in_play_pokemon[0][2] = Some(benched);
+
+        state.return_to_hand(0, 2);
+
+        assert!(state.in_play_pokemon[0][2].is_none());
+        assert_eq!(
+            state.hands[0],
+            vec![bulbasaur, ivysaur, ToolId::A2147GiantCape.to_card()]
+        );
+        assert_eq!(state.discard_energies[0], vec![EnergyType::Grass]);
+        // Only vacating the Active Spot asks for a promotion
+        assert!(state.move_generation_stack.is_empty());
+
+        state.in_play_pokemon[0][1] = Some(to_playable_card(
+            &get_card_by_enum(CardId::A1053Squirtle),
+            false,
+        ));
+        state.return_to_hand(0, 0);
+        assert!(state.in_play_pokemon[0][0].is_none());
+        assert_eq!(
+            state.move_generation_stack,
+            vec![(0, vec![SimpleAction::Activate { in_play_idx: 1 }])]
+        );
+    }
+
     #[test]
     fn test_players_start_with_five_cards_one_of_which_is_basic() {
         let (deck_a, deck_b) = load_test_decks();
@@ -412,6 +1189,449 @@ mod tests {
         assert_eq!(state.decks[1].cards.len(), 15);
         assert!(state.hands[0].iter().any(is_basic));
         assert!(state.hands[1].iter().any(is_basic));
+        assert_eq!(state.is_legal(), Ok(()));
+    }
+
+    fn legal_mid_game_state() -> State {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::initialize(&deck_a, &deck_b, &mut StdRng::seed_from_u64(0));
+        for player in 0..2 {
+            let basic_idx = state.hands[player]
+                .iter()
+                .position(is_basic)
+                .expect("Opening hand should have a Basic");
+            let basic = state.hands[player].remove(basic_idx);
+            state.in_play_pokemon[player][0] = Some(to_playable_card(&basic, false));
+        }
+        state.turn_count = 1;
+        state
+    }
+
+    #[test]
+    fn test_is_legal_reports_hp_above_total() {
+        let mut state = legal_mid_game_state();
+        assert_eq!(state.is_legal(), Ok(()));
+
+        let active = state.get_active_mut(1);
+        active.remaining_hp = active.total_hp + 10;
+        assert_eq!(
+            state.is_legal(),
+            Err(vec![StateError::HpAboveTotal {
+                player: 1,
+                in_play_idx: 0
+            }])
+        );
+    }
+
+    #[test]
+    fn test_is_legal_reports_cards_out_of_thin_air() {
+        let mut state = legal_mid_game_state();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.hands[0].extend([bulbasaur.clone(), bulbasaur.clone(), bulbasaur]);
+
+        let errors = state.is_legal().unwrap_err();
+        assert!(errors.contains(&StateError::WrongCardCount {
+            player: 0,
+            count: 23
+        }));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            StateError::TooManyCopies { player: 0, name, count } if name == "Bulbasaur" && *count >= 3
+        )));
+    }
+
+    #[test]
+    fn test_is_legal_reports_missing_active_unless_promoting() {
+        let mut state = legal_mid_game_state();
+        let active = state.in_play_pokemon[0][0].take();
+        state.in_play_pokemon[0][1] = active;
+        assert_eq!(
+            state.is_legal(),
+            Err(vec![StateError::MissingActive { player: 0 }])
+        );
+
+        state.push_pending_choice(0, PendingChoice::PromoteActive);
+        assert_eq!(state.is_legal(), Ok(()));
+    }
+
+    #[test]
+    fn test_evolution_actions_include_rare_candy_evolutions() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.turn_count = 5;
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let ivysaur = get_card_by_enum(CardId::A1002Ivysaur);
+        let venusaur = get_card_by_enum(CardId::A1003Venusaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.hands[0] = vec![ivysaur.clone(), venusaur.clone(), ivysaur.clone()];
+
+        // Without Rare Candy, only the normal evolution (deduplicated)
+        assert_eq!(
+            state.evolution_actions(0),
+            vec![SimpleAction::Evolve(ivysaur.clone(), 0)]
+        );
+
+        let rare_candy = get_card_by_enum(CardId::A3144RareCandy);
+        state.hands[0].extend([rare_candy.clone(), rare_candy.clone()]);
+        let play_rare_candy = SimpleAction::Play {
+            trainer_card: rare_candy.as_trainer(),
+        };
+        assert_eq!(
+            state.evolution_actions(0),
+            vec![
+                SimpleAction::Evolve(ivysaur.clone(), 0),
+                play_rare_candy.clone()
+            ]
+        );
+
+        // Playing the Rare Candy consumes it and queues the Stage 2 evolution
+        apply_action(
+            &mut StdRng::seed_from_u64(0),
+            &mut state,
+            &Action::new(0, play_rare_candy, false),
+        );
+        assert_eq!(
+            state.hands[0]
+                .iter()
+                .filter(|card| is_rare_candy(card))
+                .count(),
+            1
+        );
+        assert_eq!(
+            state.move_generation_stack.last(),
+            Some(&(0, vec![SimpleAction::Evolve(venusaur.clone(), 0)]))
+        );
+
+        // Item lock also locks Rare Candy
+        state.move_generation_stack.clear();
+        state.add_turn_effect(TurnEffect::NoItemCards, 0);
+        assert_eq!(
+            state.evolution_actions(0),
+            vec![SimpleAction::Evolve(ivysaur, 0)]
+        );
+    }
+
+    #[test]
+    fn test_total_energy_attached_with_jungle_totem() {
+        let mut state = State::default();
+        let serperior = get_card_by_enum(CardId::A1a006Serperior);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        let mut bench = to_playable_card(&serperior, false);
+        bench.attached_energy = vec![EnergyType::Grass, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[0][1] = Some(bench);
+
+        assert_eq!(state.total_energy_attached(0, false), 4);
+        assert_eq!(state.total_energy_attached(0, true), 7);
+        assert_eq!(state.total_energy_attached(1, true), 0);
+    }
+
+    #[test]
+    fn test_json_round_trip_mid_game() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut state = State::initialize(&deck_a, &deck_b, &mut rng);
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.apply_damage(30);
+        active.attached_energy = vec![EnergyType::Grass];
+        active.add_effect(CardEffect::NoRetreat, 1);
+        state.in_play_pokemon[0][0] = Some(active);
+        state.in_play_pokemon[1][0] = Some(to_playable_card(&bulbasaur, false));
+        state.turn_count = 4;
+        state.points = [1, 2];
+        state.add_turn_effect(TurnEffect::IncreasedDamage { amount: 10 }, 0);
+        state.queue_draw_action(1, 1);
+
+        let json = state.to_json();
+        let restored = State::from_json(&json).unwrap();
+        assert_eq!(restored, state);
+        assert_eq!(
+            restored.get_active(0).get_active_effects(),
+            vec![CardEffect::NoRetreat]
+        );
+    }
+
+    #[test]
+    fn test_from_json_rejects_inconsistent_state() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut active = to_playable_card(&bulbasaur, false);
+        active.remaining_hp = active.total_hp + 10;
+        state.in_play_pokemon[0][0] = Some(active);
+        assert!(State::from_json(&state.to_json()).is_err());
+
+        let state = State {
+            current_player: 2,
+            ..Default::default()
+        };
+        assert!(State::from_json(&state.to_json()).is_err());
+    }
+
+    #[test]
+    fn test_public_view_hides_opponent_hand() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let own_card = get_card_by_enum(CardId::A1001Bulbasaur);
+        let hidden_card = get_card_by_enum(CardId::A1177Weezing);
+        state.hands[0] = vec![own_card.clone()];
+        state.hands[1] = vec![hidden_card.clone(), hidden_card.clone()];
+
+        let view = state.public_view_for(0);
+        assert_eq!(view.hand, vec![own_card]);
+        assert_eq!(view.hand_sizes, [1, 2]);
+        assert_eq!(view.deck_sizes, [20, 20]);
+
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(!json.contains(&hidden_card.get_id()));
+        assert!(!json.contains(&hidden_card.get_name()));
+        let round_trip: PublicState = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, view);
+    }
+
+    #[test]
+    fn test_observe_redacts_opponent_hand_and_decks() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        let hidden_card = get_card_by_enum(CardId::A1177Weezing);
+        state.hands[0] = vec![hidden_card.clone(), hidden_card.clone()];
+        state.hands[1] = vec![get_card_by_enum(CardId::A1001Bulbasaur)];
+
+        let observation = state.observe(1);
+        assert!(!observation.hand.contains(&hidden_card));
+        assert_eq!(observation.hand_sizes[0], 2);
+
+        let json = serde_json::to_string(&observation).unwrap();
+        assert!(!json.contains(&hidden_card.get_id()));
+        for card in deck_a.cards.iter().chain(deck_b.cards.iter()) {
+            assert!(!json.contains(&card.get_id()) || state.hands[1].contains(card));
+        }
+    }
+
+    #[test]
+    fn test_simulate_turn_advances_turn_without_touching_original() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut rng = StdRng::seed_from_u64(7);
+        let state = State::initialize(&deck_a, &deck_b, &mut rng);
+        let mut player = RandomPlayer {
+            deck: deck_a.clone(),
+        };
+
+        let after_setup = state.simulate_turn(&mut player, &mut rng);
+        assert_eq!(state.turn_count, 0);
+        assert!(after_setup.turn_count > 0 || after_setup.winner.is_some());
+
+        let after_turn = after_setup.simulate_turn(&mut player, &mut rng);
+        assert!(after_turn.turn_count > after_setup.turn_count || after_turn.winner.is_some());
+    }
+
+    #[test]
+    fn test_enumerate_damaged_pokemon_skips_full_hp() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        let mut damaged = to_playable_card(&bulbasaur, false);
+        damaged.remaining_hp = 40;
+        state.in_play_pokemon[0][2] = Some(damaged);
+
+        let damaged_slots: Vec<usize> =
+            state.enumerate_damaged_pokemon(0).map(|(i, _)| i).collect();
+        assert_eq!(damaged_slots, vec![2]);
+        assert_eq!(state.enumerate_damaged_pokemon(1).count(), 0);
+    }
+
+    #[test]
+    fn test_damage_on_bench_slots() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut damaged = to_playable_card(&bulbasaur, false);
+        damaged.remaining_hp = 40;
+        state.in_play_pokemon[0][1] = Some(damaged);
+        state.in_play_pokemon[0][2] = Some(to_playable_card(&bulbasaur, false));
+
+        assert_eq!(state.damage_on(0, 1), 30);
+        assert_eq!(state.damage_on(0, 2), 0);
+        assert_eq!(state.damage_on(0, 3), 0);
+    }
+
+    #[test]
+    fn test_turn_player_stays_with_attacker_during_opponent_promotion() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        let mut attacker = to_playable_card(&bulbasaur, false);
+        attacker.attached_energy = vec![EnergyType::Grass, EnergyType::Grass];
+        state.in_play_pokemon[0][0] = Some(attacker);
+        let mut defender = to_playable_card(&bulbasaur, false);
+        defender.remaining_hp = 10;
+        state.in_play_pokemon[1][0] = Some(defender);
+        state.in_play_pokemon[1][1] = Some(to_playable_card(&bulbasaur, false));
+        assert_eq!(state.next_actor(), 0);
+
+        let attack = crate::actions::Action::new(0, SimpleAction::Attack(0), false);
+        crate::actions::apply_action(&mut rng, &mut state, &attack);
+
+        assert_eq!(state.turn_player(), 0);
+        assert_eq!(state.next_actor(), 1);
+    }
+
+    #[test]
+    fn test_attach_energy_from_discard() {
+        let mut state = State::default();
+        let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&bulbasaur, false));
+        state.discard_energies[0] = vec![EnergyType::Grass, EnergyType::Water];
+
+        assert!(state.attach_energy_from_discard(0, 0, EnergyType::Grass));
+        assert_eq!(state.discard_energies[0], vec![EnergyType::Water]);
+        assert_eq!(state.get_active(0).attached_energy, vec![EnergyType::Grass]);
+
+        // No Fire in discard, and no Pokemon on the bench: nothing changes.
+        assert!(!state.attach_energy_from_discard(0, 0, EnergyType::Fire));
+        assert!(!state.attach_energy_from_discard(0, 1, EnergyType::Water));
+        assert_eq!(state.discard_energies[0], vec![EnergyType::Water]);
+        assert_eq!(state.get_active(0).attached_energy, vec![EnergyType::Grass]);
+    }
+
+    #[test]
+    fn test_cheapest_unlocked_attack() {
+        let mut state = State::default();
+        // Charizard ex: Slash costs [R, C, C], Crimson Storm costs [R, R, C, C]
+        let charizard = get_card_by_enum(CardId::A1036CharizardEx);
+        let mut active = to_playable_card(&charizard, false);
+        active.attached_energy = vec![EnergyType::Fire, EnergyType::Water];
+        state.in_play_pokemon[0][0] = Some(active);
+        assert_eq!(state.cheapest_unlocked_attack(0), Some((0, 1)));
+        assert_eq!(state.cheapest_unlocked_attack(1), None);
+
+        // Slash is affordable, so Crimson Storm is the closest to being unlocked
+        state
+            .get_active_mut(0)
+            .attached_energy
+            .push(EnergyType::Water);
+        assert_eq!(state.cheapest_unlocked_attack(0), Some((1, 1)));
+
+        state
+            .get_active_mut(0)
+            .attached_energy
+            .push(EnergyType::Fire);
+        assert_eq!(state.cheapest_unlocked_attack(0), None);
+    }
+
+    #[test]
+    fn test_is_bench_full() {
+        let mut state = State::default();
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[0] = [
+            Some(bulbasaur.clone()),
+            Some(bulbasaur.clone()),
+            None,
+            Some(bulbasaur.clone()),
+        ];
+        assert!(!state.is_bench_full(0));
+
+        state.in_play_pokemon[0][2] = Some(bulbasaur);
+        assert!(state.is_bench_full(0));
+        assert!(!state.is_bench_full(1));
+    }
+
+    #[test]
+    fn test_available_bench_slots() {
+        let mut state = State::default();
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        assert_eq!(state.available_bench_slots(0), 3);
+        assert_eq!(state.first_open_bench_slot(0), Some(1));
+
+        // Partially filled: the Active Spot doesn't count as a Bench slot
+        state.in_play_pokemon[0] = [
+            Some(bulbasaur.clone()),
+            Some(bulbasaur.clone()),
+            None,
+            Some(bulbasaur.clone()),
+        ];
+        assert_eq!(state.available_bench_slots(0), 1);
+        assert_eq!(state.first_open_bench_slot(0), Some(2));
+
+        // Full bench
+        state.in_play_pokemon[0][2] = Some(bulbasaur);
+        assert_eq!(state.available_bench_slots(0), 0);
+        assert_eq!(state.first_open_bench_slot(0), None);
+    }
+
+    #[test]
+    fn test_kos_remaining_counts_ex_double() {
+        let mut state = State::default();
+        let bulbasaur = to_playable_card(&get_card_by_enum(CardId::A1001Bulbasaur), false);
+        state.in_play_pokemon[0][0] = Some(bulbasaur.clone());
+        state.in_play_pokemon[1][0] = Some(bulbasaur.clone());
+        assert_eq!(state.kos_remaining(0), 3);
+
+        let charizard_ex = get_card_by_enum(CardId::A1036CharizardEx);
+        state.in_play_pokemon[0][0] = Some(to_playable_card(&charizard_ex, false));
+        assert_eq!(state.kos_remaining(1), 2);
+        state.points[1] = 1;
+        assert_eq!(state.kos_remaining(1), 1);
+        assert_eq!(state.kos_remaining(0), 3);
+    }
+
+    #[test]
+    fn test_needs_mulligan_without_basic_in_hand() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        state.hands[0] = vec![get_card_by_enum(CardId::A1002Ivysaur)];
+        state.hands[1] = vec![get_card_by_enum(CardId::A1001Bulbasaur)];
+
+        assert!(state.needs_mulligan(0));
+        assert!(!state.needs_mulligan(1));
+    }
+
+    #[test]
+    fn test_resolve_mulligan_redraws_hand_without_basic() {
+        let (deck_a, deck_b) = load_test_decks();
+        let mut state = State::new(&deck_a, &deck_b);
+        for _ in 0..5 {
+            let idx = state.decks[0]
+                .cards
+                .iter()
+                .position(|card| !card.is_basic())
+                .expect("Test deck should have evolutions or trainers");
+            let card = state.decks[0].cards.remove(idx);
+            state.hands[0].push(card);
+        }
+        assert!(state.needs_mulligan(0));
+
+        state.resolve_mulligan(0, &mut StdRng::seed_from_u64(0));
+
+        assert!(!state.needs_mulligan(0));
+        assert!(state.hands[0].iter().any(is_basic));
+        assert_eq!(state.hands[0].len(), 5);
+        assert_eq!(state.decks[0].cards.len(), 15);
+    }
+
+    #[test]
+    fn test_evolution_heavy_deck_gets_legal_opening_hand() {
+        let evolution_heavy = Deck::from_string(
+            "1 Bulbasaur A1 1
+2 Ivysaur A1 2
+2 Venusaur A1 3
+2 Charmeleon A1 34
+2 Charizard A1 35
+2 Wartortle A1 54
+2 Blastoise A1 55
+2 Primeape A1 142",
+        )
+        .expect("Valid Deck Format");
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let state = State::initialize(&evolution_heavy, &evolution_heavy, &mut rng);
+
+            assert!(!state.needs_mulligan(0));
+            assert!(!state.needs_mulligan(1));
+            assert_eq!(state.hands[0].len(), 5);
+            assert_eq!(state.hands[1].len(), 5);
+        }
     }
 
     #[test]
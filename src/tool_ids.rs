@@ -2,12 +2,15 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::{
-    models::{EnergyType, PlayedCard, TrainerCard},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{Card, EnergyType, PlayedCard, TrainerCard},
     State,
 };
 
 // TODO: Probably best to generate this file from database.json via card_enum_generator.rs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum ToolId {
     A2147GiantCape,
     A2148RockyHelmet,
@@ -41,6 +44,16 @@ impl ToolId {
         TOOL_ID_MAP.get(&trainer_card.id.as_str())
     }
 
+    /// The Trainer card for this tool (e.g. to put it back in hand or into the discard pile)
+    pub fn to_card(&self) -> Card {
+        let card_id = TOOL_ID_MAP
+            .iter()
+            .find(|(_, tool_id)| *tool_id == self)
+            .and_then(|(id, _)| CardId::from_card_id(id))
+            .expect("Every ToolId should map to a known card");
+        get_card_by_enum(card_id)
+    }
+
     /// Check if a tool can be attached to a specific pokemon
     pub fn can_attach_to(&self, pokemon: &PlayedCard) -> bool {
         match self {
@@ -74,7 +87,7 @@ impl ToolId {
         let tool_id = *self;
         state
             .enumerate_in_play_pokemon(actor)
-            .filter(|(_, x)| !x.has_tool_attached())
+            .filter(|(_, x)| x.can_attach_tool())
             .filter(move |(_, x)| tool_id.can_attach_to(x))
     }
 }
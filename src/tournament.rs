@@ -0,0 +1,111 @@
+use rayon::prelude::*;
+
+use crate::{
+    players::{create_players, fill_code_array},
+    state::GameOutcome,
+    Deck, Game,
+};
+
+/// Outcomes of a series of games between two decks (deck A always goes as player 0).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MatchupResult {
+    pub wins_a: u32,
+    pub wins_b: u32,
+    /// Ties and games that hit the turn limit.
+    pub draws: u32,
+}
+
+impl MatchupResult {
+    pub fn total_games(&self) -> u32 {
+        self.wins_a + self.wins_b + self.draws
+    }
+}
+
+/// Aggregated results of a round robin. `matrix[i][j]` holds the games where deck `i` was
+/// player 0 against deck `j` (including mirror matches on the diagonal).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standings {
+    pub matrix: Vec<Vec<MatchupResult>>,
+    /// Fraction of all its games (as either player) that each deck won.
+    pub win_rates: Vec<f32>,
+}
+
+/// Plays `num_games` games between two decks with random players, seeding game `i` with
+/// `base_seed + i` so runs are reproducible.
+pub fn run_matchup(
+    deck_a: &Deck,
+    deck_b: &Deck,
+    num_games: usize,
+    base_seed: u64,
+) -> MatchupResult {
+    let mut result = MatchupResult::default();
+    for i in 0..num_games {
+        let players = create_players(deck_a.clone(), deck_b.clone(), fill_code_array(None));
+        let mut game = Game::new(players, base_seed.wrapping_add(i as u64));
+        match game.play() {
+            Some(GameOutcome::Win(0)) => result.wins_a += 1,
+            Some(GameOutcome::Win(_)) => result.wins_b += 1,
+            Some(GameOutcome::Tie) | None => result.draws += 1,
+        }
+    }
+    result
+}
+
+/// Plays every ordered pair of `decks` (each deck goes first against every deck, itself
+/// included) for `games_per_pair` games, in parallel across pairings.
+pub fn run_round_robin(decks: &[Deck], games_per_pair: usize, base_seed: u64) -> Standings {
+    let n = decks.len();
+    let results: Vec<MatchupResult> = (0..n * n)
+        .into_par_iter()
+        .map(|pair| {
+            let (i, j) = (pair / n, pair % n);
+            let seed = base_seed.wrapping_add((pair * games_per_pair) as u64);
+            run_matchup(&decks[i], &decks[j], games_per_pair, seed)
+        })
+        .collect();
+    let matrix: Vec<Vec<MatchupResult>> = results.chunks(n).map(|row| row.to_vec()).collect();
+
+    let win_rates = (0..n)
+        .map(|deck| {
+            let wins: u32 = (0..n)
+                .map(|other| matrix[deck][other].wins_a + matrix[other][deck].wins_b)
+                .sum();
+            let games: u32 = (0..n)
+                .map(|other| matrix[deck][other].total_games() + matrix[other][deck].total_games())
+                .sum();
+            if games == 0 {
+                0.0
+            } else {
+                wins as f32 / games as f32
+            }
+        })
+        .collect();
+
+    Standings { matrix, win_rates }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::load_test_deck;
+
+    #[test]
+    fn test_round_robin_matrix_is_square_and_complete() {
+        let decks = vec![
+            load_test_deck("venusaur-exeggutor.txt"),
+            load_test_deck("weezing-arbok.txt"),
+            load_test_deck("venusaur-exeggutor.txt"),
+        ];
+        let standings = run_round_robin(&decks, 2, 0);
+
+        assert_eq!(standings.matrix.len(), 3);
+        for row in &standings.matrix {
+            assert_eq!(row.len(), 3);
+            for result in row {
+                assert_eq!(result.total_games(), 2);
+            }
+        }
+        assert_eq!(standings.win_rates.len(), 3);
+        assert!(standings.win_rates.iter().all(|x| (0.0..=1.0).contains(x)));
+    }
+}
@@ -228,11 +228,15 @@ pub(crate) fn render_pokemon_card<'a>(
 
             let mut lines = vec![name_hp_line];
 
-            // Add tool name if attached
-            if let Some(tool_id) = played_card.attached_tool {
-                let tool_name = tool_id_to_name(tool_id);
+            // Add tool names if attached
+            if played_card.has_tool_attached() {
+                let tool_names: Vec<&str> = played_card
+                    .attached_tools
+                    .iter()
+                    .map(|tool_id| tool_id_to_name(*tool_id))
+                    .collect();
                 lines.push(Line::from(vec![Span::styled(
-                    format!("🔧{}", tool_name),
+                    format!("🔧{}", tool_names.join(", ")),
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::ITALIC),
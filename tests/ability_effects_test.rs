@@ -85,3 +85,60 @@ fn test_serperior_jungle_totem_ability() {
         assert_eq!(index, 0, "Attack index should be 0 (Vine Whip)");
     }
 }
+
+#[test]
+fn test_pidgeot_drive_off_offered_once_per_turn() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.in_play_pokemon[0][1] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1188Pidgeot),
+        130,
+        130,
+        vec![],
+        false,
+        vec![],
+    ));
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(bulbasaur, 40, 70, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    game.set_state(state.clone());
+
+    let (_, actions) = generate_possible_actions(&state);
+    let ability_actions: Vec<_> = actions
+        .iter()
+        .filter(|x| matches!(x.action, SimpleAction::UseAbility { .. }))
+        .collect();
+    assert_eq!(ability_actions.len(), 1);
+    assert_eq!(
+        ability_actions[0].action,
+        SimpleAction::UseAbility { in_play_idx: 1 }
+    );
+
+    // Opponent picks their new Active, then the ability is no longer offered
+    game.apply_action(ability_actions[0]);
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 1);
+    assert_eq!(actions[0].action, SimpleAction::Activate { in_play_idx: 1 });
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_remaining_hp(1, 0), 40);
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    assert!(!actions
+        .iter()
+        .any(|x| matches!(x.action, SimpleAction::UseAbility { .. })));
+}
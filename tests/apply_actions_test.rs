@@ -34,7 +34,7 @@ fn test_initial_build_phase() {
 
     // Decks should have 15 cards
     assert_eq!(state.decks[state.current_player].cards.len(), 15);
-    assert_eq!(state.decks[(state.current_player + 1) % 2].cards.len(), 15);
+    assert_eq!(state.decks[state.opponent()].cards.len(), 15);
 }
 
 #[test]
@@ -91,11 +91,7 @@ fn test_end_turn() {
     let mut game = get_initialized_game(rand::random());
     let state = game.get_state_clone();
     let current_player = state.current_player;
-    let action = Action {
-        actor: current_player,
-        action: SimpleAction::EndTurn,
-        is_stack: false,
-    };
+    let action = Action::new(current_player, SimpleAction::EndTurn, false);
     game.apply_action(&action);
     let state = game.get_state_clone();
     assert_ne!(current_player, state.current_player);
@@ -106,16 +102,30 @@ fn test_draw_action() {
     let mut game = get_initialized_game(rand::random());
     let state = game.get_state_clone();
     let deck_size = state.decks[state.current_player].cards.len();
-    let action = Action {
-        actor: state.current_player,
-        action: SimpleAction::DrawCard { amount: 1 },
-        is_stack: false,
-    };
+    let action = Action::new(
+        state.current_player,
+        SimpleAction::DrawCard { amount: 1 },
+        false,
+    );
     game.apply_action(&action);
     let state = game.get_state_clone();
     assert_eq!(deck_size - 1, state.decks[state.current_player].cards.len());
 }
 
+#[test]
+fn test_draw_action_draws_the_full_amount() {
+    let mut game = get_initialized_game(0);
+    let state = game.get_state_clone();
+    let player = state.current_player;
+    let deck_size = state.decks[player].cards.len();
+    let hand_size = state.hands[player].len();
+    let action = Action::new(player, SimpleAction::DrawCard { amount: 2 }, false);
+    game.apply_action(&action);
+    let state = game.get_state_clone();
+    assert_eq!(state.decks[player].cards.len(), deck_size - 2);
+    assert_eq!(state.hands[player].len(), hand_size + 2);
+}
+
 #[test]
 fn test_play_pokeball_action() {
     let mut game = get_initialized_game(0);
@@ -129,11 +139,7 @@ fn test_play_pokeball_action() {
         };
 
         let deck_size = state.decks[state.current_player].cards.len();
-        let action = Action {
-            actor: state.current_player,
-            action,
-            is_stack: false,
-        };
+        let action = Action::new(state.current_player, action, false);
         game.apply_action(&action);
 
         let state = game.get_state_clone();
@@ -158,11 +164,7 @@ fn test_place_action() {
     assert_eq!(state.enumerate_bench_pokemon(current_player).count(), 0); // no bench
     game.set_state(state);
 
-    let action = Action {
-        actor: current_player,
-        action,
-        is_stack: false,
-    };
+    let action = Action::new(current_player, action, false);
     game.apply_action(&action);
 
     let state = game.get_state_clone();
@@ -194,11 +196,7 @@ fn test_attach_action() {
         .iter()
         .any(|x| matches!(x.action, SimpleAction::Attach { .. })));
 
-    let action = Action {
-        actor,
-        action,
-        is_stack: false,
-    };
+    let action = Action::new(actor, action, false);
     game.apply_action(&action);
 
     let state = game.get_state_clone();
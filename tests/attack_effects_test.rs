@@ -3,6 +3,7 @@ use deckgym::{
     actions::{Action, SimpleAction},
     card_ids::CardId,
     database::get_card_by_enum,
+    generate_possible_actions,
     models::{EnergyType, PlayedCard},
     tool_ids::ToolId,
 };
@@ -38,11 +39,7 @@ fn test_weedle_multiply_attack() {
     game.set_state(state);
 
     // Apply Multiply attack
-    let attack_action = Action {
-        actor: 0,
-        action: SimpleAction::Attack(0), // First attack (Multiply)
-        is_stack: false,
-    };
+    let attack_action = Action::new(0, SimpleAction::Attack(0), false);
     game.apply_action(&attack_action);
 
     let state = game.get_state_clone();
@@ -126,7 +123,7 @@ fn test_dialga_rocky_helmet_knockout_with_energy_attach() {
     );
     // Attach Rocky Helmet
     let mut squirtle_with_tool = squirtle_played;
-    squirtle_with_tool.attached_tool = Some(ToolId::A2148RockyHelmet);
+    squirtle_with_tool.attached_tools = vec![ToolId::A2148RockyHelmet];
     state.in_play_pokemon[1][0] = Some(squirtle_with_tool);
 
     // Add 1 bench Pokémon for Player 1
@@ -150,11 +147,7 @@ fn test_dialga_rocky_helmet_knockout_with_energy_attach() {
     game.set_state(state);
 
     // Apply the Attack action (index 0 = Metallic Turbo)
-    let attack_action = Action {
-        actor: 0,
-        action: SimpleAction::Attack(0),
-        is_stack: false,
-    };
+    let attack_action = Action::new(0, SimpleAction::Attack(0), false);
     game.apply_action(&attack_action);
 
     // The attack should queue up an energy attachment action
@@ -215,3 +208,181 @@ fn test_dialga_rocky_helmet_knockout_with_energy_attach() {
         total_metal_on_bench
     );
 }
+
+/// Sets up Cubone (p0, 100 HP for easy math) vs Charmeleon (p1) and optionally uses Growl before
+/// passing the turn. Returns Cubone's remaining HP after Charmeleon's Fire Claws (60 damage).
+fn cubone_hp_after_fire_claws(use_growl: bool) -> u32 {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1151Cubone),
+        100,
+        100,
+        vec![EnergyType::Fighting],
+        false,
+        vec![],
+    ));
+    state.in_play_pokemon[1][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1034Charmeleon),
+        90,
+        90,
+        vec![EnergyType::Fire, EnergyType::Fire, EnergyType::Fire],
+        false,
+        vec![],
+    ));
+    game.set_state(state);
+
+    let action = if use_growl {
+        SimpleAction::Attack(0)
+    } else {
+        SimpleAction::EndTurn
+    };
+    game.apply_action(&Action::new(0, action, false));
+    if use_growl {
+        // Attacking queues the EndTurn
+        game.apply_action(&Action::new(0, SimpleAction::EndTurn, true));
+    }
+    assert_eq!(game.get_state_clone().current_player, 1);
+
+    game.apply_action(&Action::new(1, SimpleAction::Attack(0), false));
+    game.get_state_clone().get_remaining_hp(0, 0)
+}
+
+#[test]
+fn test_cubone_growl_reduces_defending_pokemon_damage_next_turn() {
+    assert_eq!(cubone_hp_after_fire_claws(false), 40);
+    assert_eq!(cubone_hp_after_fire_claws(true), 60);
+}
+
+/// Smoochum's Shivery Wave against a 200 HP Bulbasaur with `defender_energy` energies attached.
+fn bulbasaur_hp_after_shivery_wave(defender_energy: usize) -> u32 {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A4075Smoochum),
+        30,
+        30,
+        vec![],
+        false,
+        vec![],
+    ));
+    state.in_play_pokemon[1][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        200,
+        200,
+        vec![EnergyType::Grass; defender_energy],
+        false,
+        vec![],
+    ));
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    game.get_state_clone().get_remaining_hp(1, 0)
+}
+
+#[test]
+fn test_smoochum_shivery_wave_scales_with_defender_energy() {
+    assert_eq!(bulbasaur_hp_after_shivery_wave(3), 140);
+    assert_eq!(bulbasaur_hp_after_shivery_wave(0), 200);
+}
+
+#[test]
+fn test_hitmonlee_stretch_kick_knocks_out_chosen_bench_pokemon() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.points = [0, 0];
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1154Hitmonlee),
+        80,
+        80,
+        vec![EnergyType::Fighting],
+        false,
+        vec![],
+    ));
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(squirtle, 20, 60, vec![], false, vec![])),
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    // Only the opponent's Benched Pokémon can be targeted
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    let targets: Vec<_> = actions
+        .iter()
+        .map(|x| match &x.action {
+            SimpleAction::ApplyDamage { targets, .. } => targets.clone(),
+            other => panic!("Unexpected action {other:?}"),
+        })
+        .collect();
+    assert_eq!(targets, vec![vec![(30, 1, 1)], vec![(30, 1, 2)]]);
+    game.apply_action(&actions[1]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.points, [1, 0]);
+    assert!(state.in_play_pokemon[1][2].is_none());
+    assert_eq!(state.get_remaining_hp(1, 0), 60);
+    assert_eq!(state.get_remaining_hp(1, 1), 60);
+    // A Benched K.O. doesn't ask the opponent to promote a new Active
+    assert_eq!(state.winner, None);
+    assert!(!state.move_generation_stack.iter().any(|(actor, choices)| {
+        *actor == 1
+            && choices
+                .iter()
+                .any(|x| matches!(x, SimpleAction::Activate { .. }))
+    }));
+}
+
+#[test]
+fn test_ember_discards_one_fire_energy_from_attacker() {
+    let charmander = get_card_by_enum(CardId::A1033Charmander);
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.discard_energies[0].clear();
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        charmander,
+        60,
+        60,
+        vec![EnergyType::Fire, EnergyType::Fire, EnergyType::Water],
+        false,
+        vec![],
+    ));
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    let state = game.get_state_clone();
+    let mut remaining = state.get_active(0).attached_energy.clone();
+    remaining.sort();
+    assert_eq!(remaining, vec![EnergyType::Fire, EnergyType::Water]);
+    assert_eq!(state.discard_energies[0], vec![EnergyType::Fire]);
+}
@@ -0,0 +1,71 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::PlayedCard,
+};
+
+mod common;
+
+#[test]
+fn test_cyrus_offers_only_damaged_bench_and_switches_opponent() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let mut poisoned_active = PlayedCard::new(bulbasaur.clone(), 70, 70, vec![], false, vec![]);
+    poisoned_active.poisoned = true;
+    state.in_play_pokemon[1] = [
+        Some(poisoned_active),
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            40,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(bulbasaur, 10, 70, vec![], false, vec![])),
+    ];
+    let cyrus = get_card_by_enum(CardId::A2150Cyrus);
+    state.hands[0].push(cyrus.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: cyrus.as_trainer(),
+        },
+        false,
+    ));
+
+    // The Cyrus player chooses among the opponent's damaged bench only
+    let state = game.get_state_clone();
+    let (actor, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::ActivateOpponent { in_play_idx: 1 },
+            SimpleAction::ActivateOpponent { in_play_idx: 3 },
+        ]
+    );
+    game.apply_action(&actions[1]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_active(1).remaining_hp, 10);
+    let displaced = state.in_play_pokemon[1][3].as_ref().unwrap();
+    assert_eq!(displaced.remaining_hp, 70);
+    assert!(!displaced.poisoned, "Moving to the Bench clears status");
+}
@@ -4,6 +4,7 @@ use deckgym::{
     card_ids::CardId,
     database::get_card_by_enum,
     models::{EnergyType, PlayedCard},
+    State,
 };
 
 mod common;
@@ -20,7 +21,7 @@ fn test_darkrai_ex_nightmare_aura() {
     let mut state = game.get_state_clone();
 
     let test_player = state.current_player;
-    let opponent_player = (test_player + 1) % 2;
+    let opponent_player = State::opponent_of(test_player);
 
     // Set up Darkrai ex in active position
     let darkrai = PlayedCard::new(
@@ -47,14 +48,14 @@ fn test_darkrai_ex_nightmare_aura() {
     game.set_state(state);
 
     // Attach Darkness energy from Energy Zone to Darkrai ex
-    let attach_action = Action {
-        actor: test_player,
-        action: SimpleAction::Attach {
+    let attach_action = Action::new(
+        test_player,
+        SimpleAction::Attach {
             attachments: vec![(1, EnergyType::Darkness, 0)],
             is_turn_energy: true,
         },
-        is_stack: false,
-    };
+        false,
+    );
 
     // Apply the action
     game.apply_action(&attach_action);
@@ -94,7 +95,7 @@ fn test_darkrai_ex_nightmare_aura_only_darkness() {
     let mut state = game.get_state_clone();
 
     let test_player = state.current_player;
-    let opponent_player = (test_player + 1) % 2;
+    let opponent_player = State::opponent_of(test_player);
 
     let darkrai = PlayedCard::new(darkrai_ex_card.clone(), 140, 140, vec![], false, vec![]);
     state.in_play_pokemon[test_player][0] = Some(darkrai);
@@ -106,14 +107,14 @@ fn test_darkrai_ex_nightmare_aura_only_darkness() {
     game.set_state(state);
 
     // Attach Fire energy from Energy Zone to Darkrai ex
-    let attach_action = Action {
-        actor: test_player,
-        action: SimpleAction::Attach {
+    let attach_action = Action::new(
+        test_player,
+        SimpleAction::Attach {
             attachments: vec![(1, EnergyType::Fire, 0)],
             is_turn_energy: true,
         },
-        is_stack: false,
-    };
+        false,
+    );
 
     game.apply_action(&attach_action);
 
@@ -141,7 +142,7 @@ fn test_darkrai_ex_nightmare_aura_only_turn_energy() {
     let mut state = game.get_state_clone();
 
     let test_player = state.current_player;
-    let opponent_player = (test_player + 1) % 2;
+    let opponent_player = State::opponent_of(test_player);
 
     let darkrai = PlayedCard::new(darkrai_ex_card.clone(), 140, 140, vec![], false, vec![]);
     state.in_play_pokemon[test_player][0] = Some(darkrai);
@@ -153,14 +154,14 @@ fn test_darkrai_ex_nightmare_aura_only_turn_energy() {
     game.set_state(state);
 
     // Attach Darkness energy NOT from Energy Zone (is_turn_energy = false, e.g., from an ability)
-    let attach_action = Action {
-        actor: test_player,
-        action: SimpleAction::Attach {
+    let attach_action = Action::new(
+        test_player,
+        SimpleAction::Attach {
             attachments: vec![(1, EnergyType::Darkness, 0)],
             is_turn_energy: false,
         },
-        is_stack: false,
-    };
+        false,
+    );
 
     game.apply_action(&attach_action);
 
@@ -0,0 +1,102 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions, generate_possible_trainer_actions,
+    models::{EnergyType, PlayedCard},
+    State,
+};
+
+mod common;
+
+/// Player 0 has a Bulbasaur in the Active Spot and another on the Bench with `bench_energy`.
+fn setup_state(bench_energy: Vec<EnergyType>) -> State {
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            70,
+            70,
+            vec![EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            bulbasaur,
+            70,
+            70,
+            bench_energy,
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    state.hands[0] = vec![get_card_by_enum(CardId::A2154Dawn)];
+    state
+}
+
+#[test]
+fn test_dawn_cannot_be_played_without_energy_on_bench() {
+    let state = setup_state(vec![]);
+    let dawn = get_card_by_enum(CardId::A2154Dawn).as_trainer();
+
+    let actions = generate_possible_trainer_actions(&state, &dawn).unwrap();
+
+    assert!(actions.is_empty());
+}
+
+#[test]
+fn test_dawn_moves_chosen_energy_from_bench_to_active() {
+    let state = setup_state(vec![EnergyType::Water, EnergyType::Fire]);
+    let mut game = get_initialized_game(0);
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: get_card_by_enum(CardId::A2154Dawn).as_trainer(),
+        },
+        false,
+    ));
+
+    let (actor, choices) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    let move_fire = SimpleAction::MoveEnergy {
+        from_in_play_idx: 1,
+        to_in_play_idx: 0,
+        energy: EnergyType::Fire,
+    };
+    assert_eq!(
+        choices.iter().map(|x| x.action.clone()).collect::<Vec<_>>(),
+        vec![
+            SimpleAction::MoveEnergy {
+                from_in_play_idx: 1,
+                to_in_play_idx: 0,
+                energy: EnergyType::Water,
+            },
+            move_fire.clone(),
+        ]
+    );
+    let choice = choices.into_iter().find(|x| x.action == move_fire).unwrap();
+    game.apply_action(&choice);
+
+    let state = game.get_state_clone();
+    assert_eq!(
+        state.get_active(0).attached_energy,
+        vec![EnergyType::Grass, EnergyType::Fire]
+    );
+    assert_eq!(
+        state.in_play_pokemon[0][1]
+            .as_ref()
+            .unwrap()
+            .attached_energy,
+        vec![EnergyType::Water]
+    );
+}
@@ -0,0 +1,75 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::PlayedCard,
+};
+
+mod common;
+
+#[test]
+fn test_eevee_bag_heals_only_eevee_evolutions() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let eevee = get_card_by_enum(CardId::A1206Eevee);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1080Vaporeon),
+            90,
+            120,
+            vec![],
+            false,
+            vec![eevee],
+        )),
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1053Squirtle),
+            30,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let eevee_bag = get_card_by_enum(CardId::A3b066EeveeBag);
+    state.hands[0] = vec![eevee_bag.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: eevee_bag.as_trainer(),
+        },
+        false,
+    ));
+
+    // Eevee Bag doesn't search the deck: it offers a choice between a damage boost and healing
+    let state = game.get_state_clone();
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::ApplyEeveeBagDamageBoost,
+            SimpleAction::HealAllEeveeEvolutions,
+        ]
+    );
+    game.apply_action(&actions[1]);
+
+    let state = game.get_state_clone();
+    assert!(state.hands[0].is_empty());
+    assert_eq!(
+        state.in_play_pokemon[0][0].as_ref().unwrap().remaining_hp,
+        110
+    );
+    assert_eq!(
+        state.in_play_pokemon[0][1].as_ref().unwrap().remaining_hp,
+        30
+    );
+}
@@ -0,0 +1,77 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::PlayedCard,
+};
+
+mod common;
+
+#[test]
+fn test_erika_heals_chosen_grass_pokemon_only() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1053Squirtle),
+            10,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            50,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(bulbasaur, 10, 70, vec![], false, vec![])),
+        None,
+    ];
+    let erika = get_card_by_enum(CardId::A1219Erika);
+    state.hands[0] = vec![erika.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: erika.as_trainer(),
+        },
+        false,
+    ));
+
+    // Only the damaged Grass Pokémon are offered, not the damaged Water Active
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::Heal {
+                in_play_idx: 1,
+                amount: 50,
+                cure_status: false,
+            },
+            SimpleAction::Heal {
+                in_play_idx: 2,
+                amount: 50,
+                cure_status: false,
+            },
+        ]
+    );
+    game.apply_action(&actions[1]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_remaining_hp(0, 0), 10);
+    assert_eq!(state.get_remaining_hp(0, 1), 50);
+    assert_eq!(state.get_remaining_hp(0, 2), 60);
+    assert!(state.hands[0].is_empty());
+}
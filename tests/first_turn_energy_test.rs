@@ -0,0 +1,47 @@
+use common::init_random_players;
+use deckgym::{actions::SimpleAction, generate_possible_actions, Game, State};
+
+mod common;
+
+/// Plays the setup phase and the opening draw, returning the first player's turn 1 state.
+fn opening_turn(first_turn_energy: bool) -> State {
+    let mut game = Game::new(init_random_players(), 0);
+    let mut state = game.get_state_clone();
+    state.first_turn_energy = first_turn_energy;
+    game.set_state(state);
+    while game.get_state_clone().turn_count == 0
+        || !game.get_state_clone().move_generation_stack.is_empty()
+    {
+        game.play_tick();
+    }
+    game.get_state_clone()
+}
+
+fn can_attach_turn_energy(state: &State) -> bool {
+    let (_, actions) = generate_possible_actions(state);
+    actions.iter().any(|x| {
+        matches!(
+            x.action,
+            SimpleAction::Attach {
+                is_turn_energy: true,
+                ..
+            }
+        )
+    })
+}
+
+#[test]
+fn test_first_player_has_no_energy_on_opening_turn() {
+    let state = opening_turn(false);
+    assert_eq!(state.turn_count, 1);
+    assert!(!state.generates_energy_this_turn(state.current_player));
+    assert!(!can_attach_turn_energy(&state));
+}
+
+#[test]
+fn test_first_turn_energy_toggle_gives_opening_energy() {
+    let state = opening_turn(true);
+    assert_eq!(state.turn_count, 1);
+    assert!(state.generates_energy_this_turn(state.current_player));
+    assert!(can_attach_turn_energy(&state));
+}
@@ -1,8 +1,18 @@
+use std::sync::{Arc, Mutex};
+
 use common::init_random_players;
 use deckgym::{
-    players::{AttachAttackPlayer, EndTurnPlayer, MctsPlayer, Player, RandomPlayer},
-    state::GameOutcome,
+    players::{
+        AttachAttackPlayer, EndTurnPlayer, FairPlayer, MctsPlayer, ObservingPlayer, Player,
+        RandomPlayer,
+    },
+    simulation_event_handler::{
+        CompositeSimulationEventHandler, GameLog, JsonLogHandler, SimulationEventHandler,
+        SnapshotRecorder,
+    },
+    state::{GameOutcome, Observation},
     test_helpers::load_test_decks,
+    Deck, State,
 };
 
 mod common;
@@ -11,7 +21,10 @@ mod common;
 fn test_game_api() {
     let players = init_random_players();
     let mut game = deckgym::Game::new(players, 0);
-    game.play();
+    while !game.is_game_over() {
+        game.play_tick();
+        assert_eq!(game.get_state_clone().is_legal(), Ok(()));
+    }
 }
 
 #[test]
@@ -30,6 +43,56 @@ fn test_mcts_player() {
     game.play();
 }
 
+#[test]
+fn test_random_players_are_reproducible_from_seed() {
+    let play_out = |seed| {
+        let mut game = deckgym::Game::new(init_random_players(), seed);
+        let mut actions = vec![];
+        while !game.is_game_over() {
+            actions.push(game.play_tick());
+        }
+        (actions, game.get_state_clone())
+    };
+
+    for seed in [0, 7, 42] {
+        assert_eq!(play_out(seed), play_out(seed));
+    }
+}
+
+#[test]
+fn test_random_players_with_multi_energy_decks_are_reproducible_from_seed() {
+    // Energy generation picks among several types here, so it must also use the game's RNG
+    let with_energies = |filename: &str, energies: &str| {
+        let decklist = std::fs::read_to_string(format!("example_decks/{filename}")).unwrap();
+        Deck::from_string(&format!("{energies}{decklist}")).unwrap()
+    };
+    let deck_a = with_energies(
+        "venusaur-exeggutor.txt",
+        "Energy: Grass\nEnergy: Fire\nEnergy: Water\n",
+    );
+    let deck_b = with_energies("weezing-arbok.txt", "Energy: Darkness\nEnergy: Psychic\n");
+    let play_out = |seed| {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(RandomPlayer {
+                deck: deck_a.clone(),
+            }),
+            Box::new(RandomPlayer {
+                deck: deck_b.clone(),
+            }),
+        ];
+        let mut game = deckgym::Game::new(players, seed);
+        let mut actions = vec![];
+        while !game.is_game_over() {
+            actions.push(game.play_tick());
+        }
+        (actions, game.get_state_clone())
+    };
+
+    for seed in [0, 7, 42] {
+        assert_eq!(play_out(seed), play_out(seed));
+    }
+}
+
 #[test]
 fn test_retreat_should_cure_poison() {
     let players = init_random_players();
@@ -55,3 +118,190 @@ fn test_first_ko() {
     assert_eq!(game.get_state_clone().turn_count, 7);
     assert_eq!(winner, Some(GameOutcome::Win(0)));
 }
+
+#[derive(Debug)]
+struct FirstActionObserver {
+    deck: deckgym::Deck,
+}
+
+impl ObservingPlayer for FirstActionObserver {
+    fn get_deck(&self) -> deckgym::Deck {
+        self.deck.clone()
+    }
+
+    fn decision_fn(
+        &mut self,
+        _: &mut rand::rngs::StdRng,
+        observation: &Observation,
+        possible_actions: &[deckgym::actions::Action],
+    ) -> deckgym::actions::Action {
+        assert_eq!(
+            observation.hand.len(),
+            observation.hand_sizes[observation.player]
+        );
+        possible_actions[0].clone()
+    }
+}
+
+#[test]
+fn test_fair_player_plays_on_observations() {
+    let (deck_a, deck_b) = load_test_decks();
+    let player_a = Box::new(FairPlayer(FirstActionObserver { deck: deck_a }));
+    let player_b = Box::new(RandomPlayer { deck: deck_b });
+    let players: Vec<Box<dyn Player>> = vec![player_a, player_b];
+    let mut game = deckgym::Game::new(players, 3);
+    game.play();
+}
+
+#[test]
+fn test_json_log_handler_emits_game_log() {
+    let players = common::init_random_players();
+    let mut game = deckgym::Game::new(players, 0);
+    let game_id = uuid::Uuid::new_v4();
+    let mut handler = JsonLogHandler::default();
+
+    handler.on_game_start(game_id);
+    let mut played_actions = vec![];
+    while !game.is_game_over() {
+        let state = game.get_state_clone();
+        let (actor, actions) = deckgym::generate_possible_actions(&state);
+        let action = game.play_tick();
+        handler.on_action(game_id, &state, actor, &actions, &action);
+        played_actions.push(action);
+    }
+    let state = game.get_state_clone();
+    handler.on_game_end(game_id, state.clone(), state.winner);
+
+    assert_eq!(handler.logs().len(), 1);
+    let log: GameLog = serde_json::from_str(&handler.logs()[0]).unwrap();
+    assert_eq!(log.game_id, game_id.to_string());
+    assert_eq!(log.outcome, state.winner);
+    assert_eq!(log.points, state.points);
+    let logged_actions: Vec<_> = log.actions.into_iter().map(|x| x.action).collect();
+    assert_eq!(logged_actions, played_actions);
+}
+
+#[test]
+fn test_empty_decks_do_not_lose_or_crash() {
+    let players = common::init_random_players();
+    let mut game = deckgym::Game::new(players, 0);
+    let mut state = game.get_state_clone();
+    state.decks[0].cards.clear();
+    state.decks[1].cards.clear();
+    let hand_sizes = [state.hands[0].len(), state.hands[1].len()];
+    game.set_state(state);
+
+    // Setup phase plus the first turn's draw step
+    while game.get_state_clone().turn_count < 2 {
+        game.play_tick();
+    }
+    let state = game.get_state_clone();
+    assert!(state.hands[0].len() <= hand_sizes[0]);
+    assert!(state.hands[1].len() <= hand_sizes[1]);
+    assert_eq!(state.winner, None, "Running out of cards is not a loss");
+
+    // The game still finishes through points or the turn limit
+    game.play();
+    let state = game.get_state_clone();
+    assert!(state.winner.is_some() || state.turn_count >= 100);
+}
+
+#[test]
+fn test_turn_log_has_one_entry_per_tick() {
+    let players = init_random_players();
+    let mut game = deckgym::Game::new(players, 0);
+    let mut last_action = None;
+    for _ in 0..20 {
+        last_action = Some(game.play_tick());
+    }
+
+    let log = game.take_turn_log();
+    assert_eq!(log.len(), 20);
+    let last = log.last().unwrap();
+    assert_eq!(Some(&last.action), last_action.as_ref());
+    assert_eq!(last.resulting_points, game.get_state_clone().points);
+    assert!(game.take_turn_log().is_empty());
+}
+
+/// Records (is_start, turn, player) for every turn boundary event.
+struct TurnBoundaryRecorder(Arc<Mutex<Vec<(bool, u8, usize)>>>);
+
+impl SimulationEventHandler for TurnBoundaryRecorder {
+    fn on_turn_start(&mut self, _: uuid::Uuid, turn: u8, player: usize, _: &State) {
+        self.0.lock().unwrap().push((true, turn, player));
+    }
+
+    fn on_turn_end(&mut self, _: uuid::Uuid, turn: u8, player: usize, _: &State) {
+        self.0.lock().unwrap().push((false, turn, player));
+    }
+
+    fn merge(&mut self, _: &dyn SimulationEventHandler) {}
+}
+
+#[test]
+fn test_turn_hooks_fire_once_per_turn_boundary() {
+    let events = Arc::new(Mutex::new(vec![]));
+    let mut handler =
+        CompositeSimulationEventHandler::new(vec![Box::new(TurnBoundaryRecorder(events.clone()))]);
+    let mut game = deckgym::Game::new_with_event_handlers(
+        uuid::Uuid::new_v4(),
+        init_random_players(),
+        0,
+        &mut handler,
+    );
+    game.play();
+    let final_turn = game.get_state_clone().turn_count;
+
+    let events = events.lock().unwrap();
+    // Each turn, starting from the setup turn 0, is opened and closed exactly once
+    assert_eq!(events.len(), 2 * (final_turn as usize + 1));
+    for (turn, pair) in events.chunks(2).enumerate() {
+        let (started, start_turn, start_player) = pair[0];
+        let (ended, end_turn, end_player) = pair[1];
+        assert!(started && !ended);
+        assert_eq!(start_turn as usize, turn);
+        assert_eq!(end_turn as usize, turn);
+        assert_eq!(start_player, end_player);
+    }
+    // Turn players alternate once the game proper starts
+    for pair in events.chunks(2).skip(1).collect::<Vec<_>>().windows(2) {
+        assert_ne!(pair[0][0].2, pair[1][0].2);
+    }
+}
+
+#[test]
+fn test_state_snapshot_only_when_no_decision_is_pending() {
+    let mut handler =
+        CompositeSimulationEventHandler::new(vec![Box::new(SnapshotRecorder::default())]);
+    let mut game = deckgym::Game::new_with_event_handlers(
+        uuid::Uuid::new_v4(),
+        init_random_players(),
+        0,
+        &mut handler,
+    );
+    let mut states = vec![];
+    while !game.is_game_over() {
+        game.play_tick();
+        states.push(game.get_state_clone());
+    }
+    drop(game);
+
+    // The game only ends on the last tick, which is always snapshotted
+    let last = states.len() - 1;
+    let expected: Vec<State> = states
+        .iter()
+        .enumerate()
+        .filter(|(i, state)| state.move_generation_stack.is_empty() || *i == last)
+        .map(|(_, state)| state.clone())
+        .collect();
+    assert!(
+        expected.len() < states.len(),
+        "Some ticks leave a pending decision"
+    );
+    let snapshots = handler
+        .get_handler::<SnapshotRecorder>()
+        .unwrap()
+        .snapshots();
+    assert_eq!(snapshots, expected.as_slice());
+    assert_eq!(snapshots.last(), states.last());
+}
@@ -0,0 +1,85 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+/// Charmander (Ember, 30 damage) vs a Squirtle with 200 HP (not weak to Fire).
+fn setup_game() -> Game<'static> {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1033Charmander),
+        60,
+        60,
+        vec![EnergyType::Fire, EnergyType::Fire, EnergyType::Fire],
+        false,
+        vec![],
+    ));
+    state.in_play_pokemon[1][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1053Squirtle),
+        200,
+        200,
+        vec![],
+        false,
+        vec![],
+    ));
+    game.set_state(state);
+    game
+}
+
+fn play_giovanni(game: &mut Game) {
+    let giovanni = get_card_by_enum(CardId::A1223Giovanni);
+    let mut state = game.get_state_clone();
+    state.hands[0].push(giovanni.clone());
+    game.set_state(state);
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: giovanni.as_trainer(),
+        },
+        false,
+    ));
+}
+
+/// Attacks with player 0's Active and returns the damage dealt to player 1's Active.
+fn attack(game: &mut Game) -> u32 {
+    let before = game.get_state_clone().get_remaining_hp(1, 0);
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    before - game.get_state_clone().get_remaining_hp(1, 0)
+}
+
+#[test]
+fn test_giovanni_raises_30_damage_attack_to_40() {
+    let mut game = setup_game();
+    assert_eq!(attack(&mut game), 30);
+
+    let mut game = setup_game();
+    play_giovanni(&mut game);
+    assert_eq!(attack(&mut game), 40);
+}
+
+#[test]
+fn test_giovanni_stacks_additively_and_clears_at_end_of_turn() {
+    let mut game = setup_game();
+    play_giovanni(&mut game);
+    play_giovanni(&mut game);
+    assert_eq!(attack(&mut game), 50);
+
+    // Pass back to player 0 (their EndTurn is queued by the attack)
+    for actor in [0, 1] {
+        game.apply_action(&Action::new(actor, SimpleAction::EndTurn, false));
+    }
+    let mut state = game.get_state_clone();
+    assert_eq!(state.current_player, 0);
+    state.move_generation_stack.clear();
+    game.set_state(state);
+    assert_eq!(attack(&mut game), 30);
+}
@@ -0,0 +1,69 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::Card,
+    Game, State,
+};
+
+mod common;
+
+fn state_with_gladion_in_hand(deck: Vec<Card>) -> (Game<'static>, State, Card) {
+    let game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.decks[0].cards = deck;
+    let gladion = get_card_by_enum(CardId::A3a067Gladion);
+    state.hands[0] = vec![gladion.clone()];
+    (game, state, gladion)
+}
+
+fn can_play(state: &State, gladion: &Card) -> bool {
+    let (_, actions) = generate_possible_actions(state);
+    actions.iter().any(|action| {
+        action.action
+            == SimpleAction::Play {
+                trainer_card: gladion.as_trainer(),
+            }
+    })
+}
+
+#[test]
+fn test_gladion_puts_type_null_or_silvally_into_hand() {
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let silvally = get_card_by_enum(CardId::A4b302Silvally);
+    let (mut game, state, gladion) =
+        state_with_gladion_in_hand(vec![bulbasaur.clone(), silvally.clone(), bulbasaur]);
+    assert!(can_play(&state, &gladion));
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: gladion.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    assert_eq!(state.hands[0], vec![silvally]);
+    assert_eq!(state.decks[0].cards.len(), 2);
+}
+
+#[test]
+fn test_gladion_unplayable_once_all_targets_are_accounted_for() {
+    let (_, mut state, gladion) = state_with_gladion_in_hand(vec![]);
+    // Mixed printings are recognized by CardId, not just the original ones
+    state.discard_piles[0] = vec![
+        get_card_by_enum(CardId::A3a060TypeNull),
+        get_card_by_enum(CardId::A4b301TypeNull),
+        get_card_by_enum(CardId::A3a074Silvally),
+    ];
+    assert!(can_play(&state, &gladion));
+
+    state.discard_piles[0].push(get_card_by_enum(CardId::A4b303Silvally));
+    assert!(!can_play(&state, &gladion));
+}
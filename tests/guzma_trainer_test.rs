@@ -0,0 +1,103 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_trainer_actions,
+    models::PlayedCard,
+    tool_ids::ToolId,
+    Game,
+};
+
+mod common;
+
+/// Player 0 holds Guzma; player 1 has a caped Bulbasaur (90 max HP) in the Active Spot with
+/// `remaining_hp` left, and a plain Bulbasaur on the Bench.
+fn setup_caped_opponent(remaining_hp: u32) -> Game<'static> {
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.points = [0, 0];
+
+    let mut caped = PlayedCard::new(bulbasaur.clone(), remaining_hp, 90, vec![], false, vec![]);
+    caped.attached_tools = vec![ToolId::A2147GiantCape];
+    state.in_play_pokemon[1] = [
+        Some(caped),
+        Some(PlayedCard::new(bulbasaur, 70, 70, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    state.discard_piles[1].clear();
+    state.hands[0] = vec![get_card_by_enum(CardId::A3151Guzma)];
+    game.set_state(state);
+    game
+}
+
+fn play_guzma(game: &mut Game) {
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: get_card_by_enum(CardId::A3151Guzma).as_trainer(),
+        },
+        false,
+    ));
+}
+
+#[test]
+fn test_guzma_discards_giant_cape_keeping_damage() {
+    let mut game = setup_caped_opponent(30);
+
+    play_guzma(&mut game);
+
+    // 60 damage taken stays on the Pokémon, now with 70 max HP
+    let state = game.get_state_clone();
+    let active = state.get_active(1);
+    assert!(active.attached_tools.is_empty());
+    assert_eq!(active.total_hp, 70);
+    assert_eq!(active.remaining_hp, 10);
+    assert_eq!(
+        state.discard_piles[1],
+        vec![get_card_by_enum(CardId::A2147GiantCape)]
+    );
+    assert_eq!(state.points, [0, 0]);
+}
+
+#[test]
+fn test_guzma_knocks_out_pokemon_kept_alive_by_giant_cape() {
+    let mut game = setup_caped_opponent(10);
+
+    play_guzma(&mut game);
+
+    // 80 damage on a 70 HP Bulbasaur: K.O.
+    let state = game.get_state_clone();
+    assert!(state.in_play_pokemon[1][0].is_none());
+    assert_eq!(state.points, [1, 0]);
+    assert!(state.discard_piles[1].contains(&get_card_by_enum(CardId::A2147GiantCape)));
+    assert!(state.discard_piles[1].contains(&get_card_by_enum(CardId::A1001Bulbasaur)));
+    assert_eq!(
+        state.move_generation_stack.last(),
+        Some(&(1, vec![SimpleAction::Activate { in_play_idx: 1 }]))
+    );
+}
+
+#[test]
+fn test_guzma_requires_opponent_tools() {
+    let game = setup_caped_opponent(30);
+    let mut state = game.get_state_clone();
+    let guzma = get_card_by_enum(CardId::A3151Guzma).as_trainer();
+    assert!(!generate_possible_trainer_actions(&state, &guzma)
+        .unwrap()
+        .is_empty());
+
+    state.in_play_pokemon[1][0]
+        .as_mut()
+        .unwrap()
+        .attached_tools
+        .clear();
+    assert!(generate_possible_trainer_actions(&state, &guzma)
+        .unwrap()
+        .is_empty());
+}
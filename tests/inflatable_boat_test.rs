@@ -0,0 +1,59 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+/// Retreats a Blastoise (retreat cost 3) with 3 Water energy attached, and returns how many
+/// energies it has left afterwards.
+fn energies_left_after_retreat(with_boat: bool) -> usize {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let mut blastoise = PlayedCard::new(
+        get_card_by_enum(CardId::A1055Blastoise),
+        150,
+        150,
+        vec![EnergyType::Water; 3],
+        false,
+        vec![],
+    );
+    if with_boat {
+        blastoise.attached_tools = vec![ToolId::A4a067InflatableBoat];
+    }
+    state.in_play_pokemon[0] = [
+        Some(blastoise),
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1053Squirtle),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Retreat(1), false));
+
+    let state = game.get_state_clone();
+    state.in_play_pokemon[0][1]
+        .as_ref()
+        .expect("Blastoise should be on the Bench")
+        .attached_energy
+        .len()
+}
+
+#[test]
+fn test_inflatable_boat_reduces_water_retreat_cost() {
+    assert_eq!(energies_left_after_retreat(false), 0);
+    assert_eq!(energies_left_after_retreat(true), 1);
+}
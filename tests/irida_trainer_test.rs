@@ -0,0 +1,73 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+};
+
+mod common;
+
+#[test]
+fn test_irida_heals_only_pokemon_with_water_energy() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            10,
+            60,
+            vec![EnergyType::Water],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            30,
+            60,
+            vec![EnergyType::Water, EnergyType::Water],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            squirtle,
+            10,
+            60,
+            vec![EnergyType::Fire],
+            false,
+            vec![],
+        )),
+        None,
+    ];
+    let irida = get_card_by_enum(CardId::A2a072Irida);
+    state.hands[0].push(irida.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: irida.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    let in_play = &state.in_play_pokemon[0];
+    let remaining_hp: Vec<u32> = in_play.iter().flatten().map(|p| p.remaining_hp).collect();
+    assert_eq!(remaining_hp, vec![50, 60, 10]);
+    // Irida's card text doesn't discard any Energy.
+    let water_counts: Vec<usize> = in_play
+        .iter()
+        .flatten()
+        .map(|p| {
+            p.attached_energy
+                .iter()
+                .filter(|e| **e == EnergyType::Water)
+                .count()
+        })
+        .collect();
+    assert_eq!(water_counts, vec![1, 2, 0]);
+}
@@ -0,0 +1,70 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+#[test]
+fn test_knockout_discards_attached_energy_and_tools() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.discard_piles = [vec![], vec![]];
+    state.discard_energies = [vec![], vec![]];
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    ));
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    let wartortle = get_card_by_enum(CardId::A1054Wartortle);
+    let mut defender = PlayedCard::new(
+        wartortle.clone(),
+        30,
+        80,
+        vec![EnergyType::Water, EnergyType::Water, EnergyType::Colorless],
+        false,
+        vec![squirtle.clone()],
+    );
+    defender.attached_tools = vec![ToolId::A2147GiantCape];
+    state.in_play_pokemon[1] = [
+        Some(defender),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    let state = game.get_state_clone();
+    assert_eq!(state.points, [1, 0]);
+    assert!(state.in_play_pokemon[1][0].is_none());
+    assert_eq!(
+        state.discard_energies[1],
+        vec![EnergyType::Water, EnergyType::Water, EnergyType::Colorless]
+    );
+    assert_eq!(
+        state.discard_piles[1],
+        vec![squirtle, wartortle, ToolId::A2147GiantCape.to_card()]
+    );
+    assert!(state.discard_energies[0].is_empty());
+    assert!(state.discard_piles[0].is_empty());
+}
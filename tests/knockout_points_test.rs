@@ -0,0 +1,54 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+};
+
+mod common;
+
+/// Player 0's Bulbasaur uses Vine Whip (40 damage) on player 1's `defender` at 10 HP,
+/// and returns the points afterwards.
+fn knock_out(defender: CardId) -> [u8; 2] {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.points = [0, 0];
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    ));
+    let defender = get_card_by_enum(defender);
+    let bench = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(defender, 10, 150, vec![], false, vec![])),
+        Some(PlayedCard::new(bench, 60, 60, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    let state = game.get_state_clone();
+    assert!(state.in_play_pokemon[1][0].is_none());
+    state.points
+}
+
+#[test]
+fn test_knocking_out_ex_awards_two_points() {
+    assert_eq!(knock_out(CardId::A1129MewtwoEx), [2, 0]);
+}
+
+#[test]
+fn test_knocking_out_non_ex_awards_one_point() {
+    // "Exeggutor" contains "ex", but only a trailing "ex" marks an ex Pokémon
+    assert_eq!(knock_out(CardId::A3002AlolanExeggutor), [1, 0]);
+}
@@ -0,0 +1,52 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    state::GameOutcome,
+    tool_ids::ToolId,
+};
+
+mod common;
+
+/// Bulbasaur's Vine Whip K.O.s a Rocky Helmet Squirtle, and the helmet K.O.s Bulbasaur back.
+/// Neither player has a Bench left to promote from.
+#[test]
+fn test_simultaneous_double_knockout_is_a_tie() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.points = [0, 0];
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1001Bulbasaur),
+            20,
+            70,
+            vec![EnergyType::Grass, EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    let mut squirtle = PlayedCard::new(
+        get_card_by_enum(CardId::A1053Squirtle),
+        40,
+        60,
+        vec![],
+        false,
+        vec![],
+    );
+    squirtle.attached_tools = vec![ToolId::A2148RockyHelmet];
+    state.in_play_pokemon[1] = [Some(squirtle), None, None, None];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    assert_eq!(game.get_state_clone().winner, Some(GameOutcome::Tie));
+    assert_eq!(game.play(), Some(GameOutcome::Tie));
+}
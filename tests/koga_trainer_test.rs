@@ -0,0 +1,84 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_trainer_actions,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+#[test]
+fn test_koga_returns_active_to_hand_and_queues_promotion() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    let player = state.current_player;
+    state.move_generation_stack.clear();
+
+    let koffing = get_card_by_enum(CardId::A1176Koffing);
+    let weezing = get_card_by_enum(CardId::A1177Weezing);
+    let mut active = PlayedCard::new(
+        weezing.clone(),
+        50,
+        110,
+        vec![EnergyType::Darkness, EnergyType::Darkness],
+        false,
+        vec![koffing.clone()],
+    );
+    active.attached_tools = vec![ToolId::A2147GiantCape];
+    state.in_play_pokemon[player] = [None, None, None, None];
+    state.in_play_pokemon[player][0] = Some(active);
+    state.in_play_pokemon[player][2] = Some(PlayedCard::new(
+        koffing.clone(),
+        70,
+        70,
+        vec![],
+        false,
+        vec![],
+    ));
+    let koga = get_card_by_enum(CardId::A1222Koga);
+    state.hands[player] = vec![koga.clone()];
+    state.discard_energies[player].clear();
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        player,
+        SimpleAction::Play {
+            trainer_card: koga.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    assert!(state.in_play_pokemon[player][0].is_none());
+    assert!(state.hands[player].contains(&weezing));
+    assert!(state.hands[player].contains(&koffing));
+    assert!(state.hands[player].contains(&get_card_by_enum(CardId::A2147GiantCape)));
+    assert_eq!(state.discard_energies[player].len(), 2);
+    assert_eq!(
+        state.move_generation_stack.last(),
+        Some(&(player, vec![SimpleAction::Activate { in_play_idx: 2 }]))
+    );
+}
+
+#[test]
+fn test_koga_cannot_be_played_without_bench() {
+    let game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    let player = state.current_player;
+    state.in_play_pokemon[player] = [None, None, None, None];
+    state.in_play_pokemon[player][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1177Weezing),
+        110,
+        110,
+        vec![],
+        false,
+        vec![],
+    ));
+    let koga = get_card_by_enum(CardId::A1222Koga).as_trainer();
+
+    let actions = generate_possible_trainer_actions(&state, &koga).unwrap();
+    assert!(actions.is_empty());
+}
@@ -0,0 +1,90 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+/// Plays Leaf and retreats `active_id` (with `energies` Water energy attached) into a benched
+/// Squirtle. Returns how many energies the retreated Pokémon has left afterwards.
+fn energies_left_after_leaf_retreat(active_id: CardId, energies: usize, with_boat: bool) -> usize {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let card = get_card_by_enum(active_id);
+    let mut active = PlayedCard::new(
+        card,
+        60,
+        60,
+        vec![EnergyType::Water; energies],
+        false,
+        vec![],
+    );
+    if with_boat {
+        active.attached_tools = vec![ToolId::A4a067InflatableBoat];
+    }
+    state.in_play_pokemon[0] = [
+        Some(active),
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1053Squirtle),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let leaf = get_card_by_enum(CardId::A1a068Leaf);
+    state.hands[0].push(leaf.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: leaf.as_trainer(),
+        },
+        false,
+    ));
+    game.apply_action(&Action::new(0, SimpleAction::Retreat(1), false));
+
+    let state = game.get_state_clone();
+    state.in_play_pokemon[0][1]
+        .as_ref()
+        .expect("Retreated Pokémon should be on the Bench")
+        .attached_energy
+        .len()
+}
+
+#[test]
+fn test_leaf_makes_two_energy_retreat_free() {
+    // Seel has a retreat cost of 2
+    assert_eq!(
+        energies_left_after_leaf_retreat(CardId::A1064Seel, 2, false),
+        2
+    );
+    // Extra reductions floor the cost at 0
+    assert_eq!(
+        energies_left_after_leaf_retreat(CardId::A1064Seel, 2, true),
+        2
+    );
+}
+
+#[test]
+fn test_leaf_stacks_with_inflatable_boat() {
+    // Blastoise has a retreat cost of 3: Leaf removes 2 and Inflatable Boat the last one
+    assert_eq!(
+        energies_left_after_leaf_retreat(CardId::A1055Blastoise, 3, false),
+        2
+    );
+    assert_eq!(
+        energies_left_after_leaf_retreat(CardId::A1055Blastoise, 3, true),
+        3
+    );
+}
@@ -0,0 +1,64 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::PlayedCard,
+};
+
+mod common;
+
+#[test]
+fn test_lillie_heals_rare_candy_stage_2() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let venusaur = get_card_by_enum(CardId::A1003Venusaur);
+    // Rare Candy skipped Ivysaur, so only the Basic is behind it
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            venusaur.clone(),
+            40,
+            160,
+            vec![],
+            false,
+            vec![bulbasaur.clone()],
+        )),
+        Some(PlayedCard::new(
+            venusaur,
+            160,
+            160,
+            vec![],
+            false,
+            vec![bulbasaur.clone()],
+        )),
+        Some(PlayedCard::new(bulbasaur, 10, 70, vec![], false, vec![])),
+        None,
+    ];
+    let lillie = get_card_by_enum(CardId::A3155Lillie);
+    state.hands[0].push(lillie.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: lillie.as_trainer(),
+        },
+        false,
+    ));
+
+    // Only the damaged Stage 2 is a valid target
+    let state = game.get_state_clone();
+    let (_, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(actions.len(), 1);
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_active(0).remaining_hp, 100);
+    assert_eq!(
+        state.in_play_pokemon[0][2].as_ref().unwrap().remaining_hp,
+        10
+    );
+}
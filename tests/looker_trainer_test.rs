@@ -0,0 +1,34 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_trainer_actions, State,
+};
+
+mod common;
+
+#[test]
+fn test_looker_is_playable_and_only_reveals() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    let player = state.current_player;
+    let opponent = State::opponent_of(player);
+    state.move_generation_stack.clear();
+    let looker = get_card_by_enum(CardId::A3a068Looker);
+    state.hands[player] = vec![looker.clone()];
+    game.set_state(state.clone());
+
+    let actions = generate_possible_trainer_actions(&state, &looker.as_trainer()).unwrap();
+    assert_eq!(actions.len(), 1);
+    game.apply_action(&Action::new(player, actions[0].clone(), false));
+
+    let after = game.get_state_clone();
+    assert!(after.hands[player].is_empty());
+    assert!(after.discard_piles[player].contains(&looker));
+    assert_eq!(after.decks[player], state.decks[player]);
+    assert_eq!(after.decks[opponent], state.decks[opponent]);
+    assert_eq!(after.hands[opponent], state.hands[opponent]);
+    assert!(after.move_generation_stack.is_empty());
+    assert!(matches!(actions[0], SimpleAction::Play { .. }));
+}
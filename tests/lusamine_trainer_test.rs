@@ -0,0 +1,76 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+};
+
+mod common;
+
+#[test]
+fn test_lusamine_attaches_two_discarded_energies_to_ultra_beast() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.points = [0, 1];
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1001Bulbasaur),
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A3a006BuzzwoleEx),
+            140,
+            140,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    state.discard_energies[0] = vec![EnergyType::Fighting, EnergyType::Water, EnergyType::Fire];
+    let lusamine = get_card_by_enum(CardId::A3a069Lusamine);
+    state.hands[0].push(lusamine.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: lusamine.as_trainer(),
+        },
+        false,
+    ));
+
+    // Only the Ultra Beast is offered as a target
+    let state = game.get_state_clone();
+    let (actor, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![SimpleAction::AttachFromDiscard {
+            in_play_idx: 1,
+            num_random_energies: 2,
+        }]
+    );
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.discard_energies[0].len(), 1);
+    let buzzwole = state.in_play_pokemon[0][1].as_ref().unwrap();
+    assert_eq!(buzzwole.attached_energy.len(), 2);
+    let mut all_energies = buzzwole.attached_energy.clone();
+    all_energies.extend(state.discard_energies[0].iter().cloned());
+    all_energies.sort_by_key(|e| format!("{e:?}"));
+    assert_eq!(
+        all_energies,
+        vec![EnergyType::Fighting, EnergyType::Fire, EnergyType::Water]
+    );
+}
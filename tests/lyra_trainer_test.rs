@@ -0,0 +1,77 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+#[test]
+fn test_lyra_switches_damaged_active_with_chosen_bench_pokemon() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    let mut damaged_active = PlayedCard::new(
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        30,
+        70,
+        vec![EnergyType::Grass],
+        false,
+        vec![],
+    );
+    damaged_active.attached_tools = vec![ToolId::A2147GiantCape];
+    damaged_active.poisoned = true;
+    state.in_play_pokemon[0] = [
+        Some(damaged_active),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+    ];
+    let lyra = get_card_by_enum(CardId::A4157Lyra);
+    state.hands[0] = vec![lyra.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: lyra.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::Activate { in_play_idx: 1 },
+            SimpleAction::Activate { in_play_idx: 3 },
+        ]
+    );
+    game.apply_action(&actions[1]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_active(0).get_name(), "Squirtle");
+    let benched = state.in_play_pokemon[0][3].as_ref().unwrap();
+    assert_eq!(benched.get_name(), "Bulbasaur");
+    assert_eq!(benched.remaining_hp, 30);
+    assert_eq!(benched.attached_energy, vec![EnergyType::Grass]);
+    assert_eq!(benched.attached_tools, vec![ToolId::A2147GiantCape]);
+    // Moving to the Bench cures Special Conditions
+    assert!(!benched.poisoned);
+}
@@ -41,11 +41,11 @@ fn test_magneton_volt_charge_attaches_lightning_energy() {
     );
 
     // Act: Use Magneton's Volt Charge ability
-    let action = Action {
-        actor: current_player,
-        action: SimpleAction::UseAbility { in_play_idx: 1 },
-        is_stack: false,
-    };
+    let action = Action::new(
+        current_player,
+        SimpleAction::UseAbility { in_play_idx: 1 },
+        false,
+    );
     game.apply_action(&action);
 
     // Assert: Magneton should now have 1 Lightning energy attached
@@ -86,11 +86,11 @@ fn test_magneton_volt_charge_can_only_be_used_once() {
     game.set_state(state);
 
     // Act: Use Magneton's Volt Charge ability first time
-    let action = Action {
-        actor: current_player,
-        action: SimpleAction::UseAbility { in_play_idx: 0 },
-        is_stack: false,
-    };
+    let action = Action::new(
+        current_player,
+        SimpleAction::UseAbility { in_play_idx: 0 },
+        false,
+    );
     game.apply_action(&action);
 
     // Assert: ability_used should be set to true
@@ -137,11 +137,11 @@ fn test_magneton_volt_charge_doesnt_end_turn() {
     game.set_state(state);
 
     // Act: Use Magneton's Volt Charge ability
-    let action = Action {
-        actor: current_player,
-        action: SimpleAction::UseAbility { in_play_idx: 0 },
-        is_stack: false,
-    };
+    let action = Action::new(
+        current_player,
+        SimpleAction::UseAbility { in_play_idx: 0 },
+        false,
+    );
     game.apply_action(&action);
 
     // Process any stack items from the ability
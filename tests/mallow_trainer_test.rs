@@ -0,0 +1,69 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+};
+
+mod common;
+
+#[test]
+fn test_mallow_fully_heals_and_discards_energy() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.discard_energies[0].clear();
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1001Bulbasaur),
+            10,
+            70,
+            vec![EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A3017Shiinotic),
+            20,
+            100,
+            vec![EnergyType::Grass, EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let mallow = get_card_by_enum(CardId::A3154Mallow);
+    state.hands[0].push(mallow.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: mallow.as_trainer(),
+        },
+        false,
+    ));
+
+    // Only the damaged Shiinotic can be chosen
+    let state = game.get_state_clone();
+    let (_, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(actions.len(), 1);
+    assert_eq!(
+        actions[0].action,
+        SimpleAction::FullHeal {
+            in_play_idx: 1,
+            discard_energies: true
+        }
+    );
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    let shiinotic = state.in_play_pokemon[0][1].as_ref().unwrap();
+    assert_eq!(shiinotic.remaining_hp, shiinotic.total_hp);
+    assert!(shiinotic.attached_energy.is_empty());
+    assert_eq!(state.discard_energies[0], vec![EnergyType::Grass; 2]);
+    assert_eq!(state.get_active(0).remaining_hp, 10);
+}
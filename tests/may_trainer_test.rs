@@ -38,13 +38,13 @@ fn test_may_generates_all_combinations() {
 
     // Execute the mutation (draw Charmander and Squirtle)
     let mut state_copy = state.clone();
-    let action = Action {
-        actor: 0,
-        action: SimpleAction::Play {
+    let action = Action::new(
+        0,
+        SimpleAction::Play {
             trainer_card: get_card_by_enum(CardId::B1223May).as_trainer().clone(),
         },
-        is_stack: false,
-    };
+        false,
+    );
 
     // Use a dummy RNG
     let mut rng = StdRng::seed_from_u64(42);
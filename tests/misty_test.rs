@@ -0,0 +1,103 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{Card, EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+fn water_pokemon(card_id: CardId) -> PlayedCard {
+    let card = get_card_by_enum(card_id);
+    PlayedCard::new(card, 60, 60, vec![], false, vec![])
+}
+
+/// Plays Misty for the current player and resolves the follow-up Attach choice (if any)
+/// by picking the first option. Returns the game after resolution.
+fn play_misty(seed: u64, water_bench: Vec<PlayedCard>) -> Game<'static> {
+    let mut game = get_initialized_game(seed);
+    let mut state = game.get_state_clone();
+    let current_player = state.current_player;
+    state.in_play_pokemon[current_player] = [None, None, None, None];
+    state.in_play_pokemon[current_player][0] = Some(water_pokemon(CardId::A1053Squirtle));
+    for (i, pokemon) in water_bench.into_iter().enumerate() {
+        state.in_play_pokemon[current_player][i + 1] = Some(pokemon);
+    }
+    let misty = get_card_by_enum(CardId::A1220Misty);
+    state.hands[current_player].push(misty.clone());
+    game.set_state(state);
+
+    let trainer_card = match misty {
+        Card::Trainer(tc) => tc,
+        _ => panic!("Expected trainer card"),
+    };
+    game.apply_action(&Action::new(
+        current_player,
+        SimpleAction::Play { trainer_card },
+        false,
+    ));
+    game
+}
+
+fn attached_water(game: &Game, player: usize, in_play_idx: usize) -> usize {
+    game.get_state_clone().in_play_pokemon[player][in_play_idx]
+        .as_ref()
+        .unwrap()
+        .attached_energy
+        .iter()
+        .filter(|e| **e == EnergyType::Water)
+        .count()
+}
+
+#[test]
+fn test_misty_flips_until_tails_with_seeded_rng() {
+    let mut results = vec![];
+    for _ in 0..2 {
+        let mut game = play_misty(28, vec![]);
+        let state = game.get_state_clone();
+        let current_player = state.current_player;
+        if let Some((actor, choices)) = state.move_generation_stack.last().cloned() {
+            assert_eq!(choices.len(), 1, "Single Water Pokémon means single target");
+            game.apply_action(&Action::new(actor, choices[0].clone(), true));
+        }
+        results.push(attached_water(&game, current_player, 0));
+    }
+
+    assert_eq!(
+        results[0], results[1],
+        "Same seed should attach same energy"
+    );
+    assert_eq!(results[0], 3);
+}
+
+#[test]
+fn test_misty_target_is_follow_up_choice_with_multiple_water_pokemon() {
+    let mut game = play_misty(28, vec![water_pokemon(CardId::A1053Squirtle)]);
+    let state = game.get_state_clone();
+    let current_player = state.current_player;
+    let (actor, choices) = state
+        .move_generation_stack
+        .last()
+        .cloned()
+        .expect("Should queue a target choice");
+    assert_eq!(actor, current_player);
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::Attach {
+                attachments: vec![(3, EnergyType::Water, 0)],
+                is_turn_energy: false,
+            },
+            SimpleAction::Attach {
+                attachments: vec![(3, EnergyType::Water, 1)],
+                is_turn_energy: false,
+            },
+        ]
+    );
+
+    game.apply_action(&Action::new(actor, choices[1].clone(), true));
+    assert_eq!(attached_water(&game, current_player, 0), 0);
+    assert_eq!(attached_water(&game, current_player, 1), 3);
+}
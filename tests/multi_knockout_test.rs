@@ -0,0 +1,121 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+/// Player 0 to act, with player 1 holding a 10 HP Active and Bench slot 1, and a healthy slot 2.
+fn game_with_weakened_opponent(attacker: CardId, energy: EnergyType) -> Game<'static> {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.points = [0, 0];
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(attacker),
+            90,
+            90,
+            vec![energy; 2],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            10,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            10,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+    ];
+    game.set_state(state);
+    game
+}
+
+/// The promotion choices queued for player 1, asserting there is exactly one such decision.
+fn promotion_choices(game: &Game) -> Vec<SimpleAction> {
+    let state = game.get_state_clone();
+    let promotions: Vec<_> = state
+        .move_generation_stack
+        .iter()
+        .filter(|(actor, choices)| {
+            *actor == 1
+                && choices
+                    .iter()
+                    .all(|x| matches!(x, SimpleAction::Activate { .. }))
+        })
+        .collect();
+    assert_eq!(promotions.len(), 1);
+    promotions[0].1.clone()
+}
+
+#[test]
+fn test_active_and_bench_knockout_awards_both_and_promotes_once() {
+    let mut game = game_with_weakened_opponent(CardId::A3a007Pheromosa, EnergyType::Grass);
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    // Jump Blues also hits a Benched Pokémon of our choice
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    let snipe_slot_1 = actions
+        .iter()
+        .find(|x| match &x.action {
+            SimpleAction::ApplyDamage { targets, .. } => targets.contains(&(20, 1, 1)),
+            _ => false,
+        })
+        .unwrap();
+    game.apply_action(snipe_slot_1);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.points, [2, 0]);
+    assert_eq!(state.winner, None);
+    assert!(state.in_play_pokemon[1][0].is_none());
+    assert!(state.in_play_pokemon[1][1].is_none());
+    assert_eq!(
+        promotion_choices(&game),
+        vec![SimpleAction::Activate { in_play_idx: 2 }]
+    );
+}
+
+#[test]
+fn test_simultaneous_poison_knockouts_promote_from_survivors() {
+    let mut game = game_with_weakened_opponent(CardId::A1001Bulbasaur, EnergyType::Grass);
+    let mut state = game.get_state_clone();
+    state.in_play_pokemon[1][0].as_mut().unwrap().poisoned = true;
+    state.in_play_pokemon[1][1].as_mut().unwrap().poisoned = true;
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::EndTurn, false));
+
+    let state = game.get_state_clone();
+    assert_eq!(state.points, [2, 0]);
+    assert_eq!(state.winner, None);
+    // The Benched Pokémon knocked out by the same Pokémon Checkup can't be promoted
+    assert_eq!(
+        promotion_choices(&game),
+        vec![SimpleAction::Activate { in_play_idx: 2 }]
+    );
+}
@@ -0,0 +1,116 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+fn attach_tool(game: &mut deckgym::Game, actor: usize, tool_id: ToolId) {
+    game.apply_action(&Action::new(
+        actor,
+        SimpleAction::AttachTool {
+            in_play_idx: 0,
+            tool_id,
+        },
+        false,
+    ));
+}
+
+#[test]
+fn test_second_tool_slot_applies_both_tools() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1001Bulbasaur),
+            70,
+            70,
+            vec![EnergyType::Grass, EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    let mut squirtle = PlayedCard::new(
+        get_card_by_enum(CardId::A1053Squirtle),
+        60,
+        60,
+        vec![],
+        false,
+        vec![],
+    );
+    squirtle.tool_capacity = 2;
+    state.in_play_pokemon[1] = [Some(squirtle), None, None, None];
+    state.hands[1] = vec![get_card_by_enum(CardId::A2148RockyHelmet)];
+    game.set_state(state);
+
+    attach_tool(&mut game, 1, ToolId::A2147GiantCape);
+
+    // A free slot is still left, so Rocky Helmet can be played on the same Pokémon
+    let mut state = game.get_state_clone();
+    state.current_player = 1;
+    let (_, actions) = generate_possible_actions(&state);
+    assert!(actions.iter().any(
+        |action| matches!(&action.action, SimpleAction::Play { trainer_card }
+            if trainer_card.id == get_card_by_enum(CardId::A2148RockyHelmet).get_id())
+    ));
+
+    attach_tool(&mut game, 1, ToolId::A2148RockyHelmet);
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+
+    let state = game.get_state_clone();
+    let squirtle = state.in_play_pokemon[1][0].as_ref().unwrap();
+    assert_eq!(
+        squirtle.attached_tools,
+        vec![ToolId::A2147GiantCape, ToolId::A2148RockyHelmet]
+    );
+    // Giant Cape: +20 HP, so Vine Whip's 40 damage leaves 40 of 80
+    assert_eq!(squirtle.total_hp, 80);
+    assert_eq!(squirtle.remaining_hp, 40);
+    // Rocky Helmet: the attacker takes 20 back
+    assert_eq!(
+        state.in_play_pokemon[0][0].as_ref().unwrap().remaining_hp,
+        50
+    );
+}
+
+#[test]
+fn test_single_tool_slot_blocks_second_tool() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(CardId::A1053Squirtle),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    state.hands[0] = vec![get_card_by_enum(CardId::A2148RockyHelmet)];
+    game.set_state(state);
+
+    attach_tool(&mut game, 0, ToolId::A2147GiantCape);
+
+    let state = game.get_state_clone();
+    let (_, actions) = generate_possible_actions(&state);
+    assert!(!actions
+        .iter()
+        .any(|action| matches!(action.action, SimpleAction::Play { .. })));
+}
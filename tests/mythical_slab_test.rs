@@ -0,0 +1,56 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::Card,
+    State,
+};
+
+mod common;
+
+/// Plays Mythical Slab for player 0 on top of the given deck and returns the resulting state.
+fn play_mythical_slab(seed: u64, deck: Vec<Card>) -> State {
+    let mut game = get_initialized_game(seed);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.decks[0].cards = deck;
+    let slab = get_card_by_enum(CardId::A1a065MythicalSlab);
+    state.hands[0] = vec![slab.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: slab.as_trainer(),
+        },
+        false,
+    ));
+    game.get_state_clone()
+}
+
+#[test]
+fn test_mythical_slab_puts_psychic_pokemon_into_hand() {
+    let mewtwo = get_card_by_enum(CardId::A1128Mewtwo);
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    for seed in [0, 1] {
+        let state = play_mythical_slab(seed, vec![mewtwo.clone(), bulbasaur.clone()]);
+        assert_eq!(state.hands[0], vec![mewtwo.clone()]);
+        assert_eq!(state.decks[0].cards, vec![bulbasaur.clone()]);
+    }
+}
+
+#[test]
+fn test_mythical_slab_puts_other_cards_on_the_bottom() {
+    let mewtwo = get_card_by_enum(CardId::A1128Mewtwo);
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    for seed in [0, 1] {
+        let state = play_mythical_slab(seed, vec![bulbasaur.clone(), mewtwo.clone()]);
+        assert!(state.hands[0].is_empty());
+        assert_eq!(
+            state.decks[0].cards,
+            vec![mewtwo.clone(), bulbasaur.clone()]
+        );
+    }
+}
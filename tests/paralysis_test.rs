@@ -0,0 +1,129 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+fn end_turn(game: &mut Game, actor: usize) {
+    game.apply_action(&Action::new(actor, SimpleAction::EndTurn, false));
+}
+
+#[test]
+fn test_paralysis_lasts_through_the_owners_next_turn() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    // Player 1's Active was just Paralyzed during player 0's turn
+    let mut paralyzed = PlayedCard::new(
+        bulbasaur,
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    );
+    paralyzed.paralyzed = true;
+    state.in_play_pokemon[1] = [
+        Some(paralyzed),
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    // It stays Paralyzed through the checkup after the opponent's turn
+    end_turn(&mut game, 0);
+    let state = game.get_state_clone();
+    assert_eq!(state.current_player, 1);
+    assert!(state.get_active(1).paralyzed);
+
+    // ...so its owner can neither attack nor retreat with it this turn
+    let (_, actions) = generate_possible_actions(&state);
+    game.apply_action(&actions[0]); // draw
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 1);
+    assert!(!actions
+        .iter()
+        .any(|x| matches!(x.action, SimpleAction::Attack(_) | SimpleAction::Retreat(_))));
+
+    // It recovers once its owner's turn is over
+    end_turn(&mut game, 1);
+    let state = game.get_state_clone();
+    assert_eq!(state.current_player, 0);
+    assert!(!state.get_active(1).paralyzed);
+}
+
+// Seeds whose attack coin flip lands heads and tails respectively
+const HEADS_SEED: u64 = 2;
+const TAILS_SEED: u64 = 0;
+
+/// Player 0's Dunsparce uses Sudden Flash on player 1's Bulbasaur; returns the defender after.
+fn defender_after_sudden_flash(seed: u64) -> PlayedCard {
+    let mut game = get_initialized_game(seed);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    let dunsparce = get_card_by_enum(CardId::A4144Dunsparce);
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            dunsparce,
+            50,
+            50,
+            vec![EnergyType::Colorless],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+        None,
+    ];
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(bulbasaur, 70, 70, vec![], false, vec![])),
+        None,
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    game.get_state_clone().get_active(1).clone()
+}
+
+#[test]
+fn test_sudden_flash_paralyzes_on_heads() {
+    let defender = defender_after_sudden_flash(HEADS_SEED);
+    assert_eq!(defender.remaining_hp, 50);
+    assert!(defender.paralyzed);
+}
+
+#[test]
+fn test_sudden_flash_only_damages_on_tails() {
+    let defender = defender_after_sudden_flash(TAILS_SEED);
+    assert_eq!(defender.remaining_hp, 50);
+    assert!(!defender.paralyzed);
+}
@@ -0,0 +1,69 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    state::GameOutcome,
+    State,
+};
+
+mod common;
+
+/// Player 0's Bulbasaur K.O.s the opponent's 10 HP Active, who still has a Bench.
+fn state_after_knockout(points_to_win: u8, defender: CardId) -> State {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.points_to_win = points_to_win;
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    state.points = [0, 0];
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    ));
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(defender),
+            10,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    game.get_state_clone()
+}
+
+#[test]
+fn test_single_point_format_ends_after_one_knockout() {
+    let state = state_after_knockout(1, CardId::A1053Squirtle);
+    assert_eq!(state.points, [1, 0]);
+    assert_eq!(state.winner, Some(GameOutcome::Win(0)));
+}
+
+#[test]
+fn test_default_format_needs_three_points() {
+    let state = state_after_knockout(3, CardId::A1053Squirtle);
+    assert_eq!(state.points, [1, 0]);
+    assert_eq!(state.winner, None);
+}
+
+#[test]
+fn test_ex_pokemon_are_still_worth_two_points() {
+    let state = state_after_knockout(4, CardId::A1004VenusaurEx);
+    assert_eq!(state.points, [2, 0]);
+    assert_eq!(state.winner, None);
+}
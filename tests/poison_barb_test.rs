@@ -0,0 +1,65 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+    Game,
+};
+
+mod common;
+
+/// Player 0's Bulbasaur (ready to use Vine Whip) attacks player 1's Bulbasaur.
+fn setup_bulbasaur_mirror(defender_tools: Vec<ToolId>) -> Game<'static> {
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        bulbasaur.clone(),
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    ));
+    let mut defender = PlayedCard::new(bulbasaur, 70, 70, vec![], false, vec![]);
+    defender.attached_tools = defender_tools;
+    state.in_play_pokemon[1][0] = Some(defender);
+    game.set_state(state);
+    game
+}
+
+fn attack(game: &mut Game) {
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+}
+
+#[test]
+fn test_poison_barb_poisons_the_attacker() {
+    let mut game = setup_bulbasaur_mirror(vec![ToolId::A3146PoisonBarb]);
+
+    attack(&mut game);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_remaining_hp(1, 0), 30);
+    assert!(
+        state.get_active(0).poisoned,
+        "Attacking a Poison Barb holder should poison the attacker"
+    );
+    assert!(!state.get_active(1).poisoned);
+}
+
+#[test]
+fn test_attacker_not_poisoned_without_poison_barb() {
+    let mut game = setup_bulbasaur_mirror(vec![]);
+
+    attack(&mut game);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_remaining_hp(1, 0), 30);
+    assert!(!state.get_active(0).poisoned);
+}
@@ -0,0 +1,76 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::Card,
+    State,
+};
+
+mod common;
+
+/// Plays Poké Ball for player 0 on top of the given deck and returns the resulting state.
+fn play_poke_ball(seed: u64, deck: Vec<Card>) -> State {
+    let mut game = get_initialized_game(seed);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.decks[0].cards = deck;
+    let poke_ball = get_card_by_enum(CardId::PA005PokeBall);
+    state.hands[0] = vec![poke_ball.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: poke_ball.as_trainer(),
+        },
+        false,
+    ));
+    game.get_state_clone()
+}
+
+fn mixed_deck() -> Vec<Card> {
+    vec![
+        get_card_by_enum(CardId::A1002Ivysaur),
+        get_card_by_enum(CardId::A1001Bulbasaur),
+        get_card_by_enum(CardId::PA001Potion),
+        get_card_by_enum(CardId::A1053Squirtle),
+        get_card_by_enum(CardId::A1054Wartortle),
+    ]
+}
+
+#[test]
+fn test_poke_ball_puts_a_basic_into_hand() {
+    for seed in 0..5 {
+        let state = play_poke_ball(seed, mixed_deck());
+        assert_eq!(state.hands[0].len(), 1);
+        assert!(state.hands[0][0].is_basic());
+        assert_eq!(state.decks[0].cards.len(), 4);
+    }
+}
+
+#[test]
+fn test_poke_ball_is_reproducible_under_a_fixed_seed() {
+    for seed in 0..5 {
+        let first = play_poke_ball(seed, mixed_deck());
+        let second = play_poke_ball(seed, mixed_deck());
+        assert_eq!(first.hands[0], second.hands[0]);
+        assert_eq!(first.decks[0].cards, second.decks[0].cards);
+    }
+}
+
+#[test]
+fn test_poke_ball_without_basics_is_still_spent() {
+    let deck = vec![
+        get_card_by_enum(CardId::A1002Ivysaur),
+        get_card_by_enum(CardId::PA001Potion),
+    ];
+    let state = play_poke_ball(0, deck);
+    assert!(state.hands[0].is_empty());
+    assert_eq!(state.decks[0].cards.len(), 2);
+    assert_eq!(
+        state.discard_piles[0].last(),
+        Some(&get_card_by_enum(CardId::PA005PokeBall))
+    );
+}
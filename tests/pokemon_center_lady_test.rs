@@ -52,16 +52,16 @@ fn test_pokemon_center_lady_heals_30_damage() {
     );
 
     // Act: Play Pokemon Center Lady
-    let play_action = Action {
-        actor: current_player,
-        action: SimpleAction::Play {
+    let play_action = Action::new(
+        current_player,
+        SimpleAction::Play {
             trainer_card: match pokemon_center_lady {
                 Card::Trainer(tc) => tc,
                 _ => panic!("Expected trainer card"),
             },
         },
-        is_stack: false,
-    };
+        false,
+    );
     game.apply_action(&play_action);
 
     // Choose to heal Bulbasaur (index 0)
@@ -122,16 +122,16 @@ fn test_pokemon_center_lady_cures_poisoned() {
     );
 
     // Act: Play Pokemon Center Lady and choose Bulbasaur
-    let play_action = Action {
-        actor: current_player,
-        action: SimpleAction::Play {
+    let play_action = Action::new(
+        current_player,
+        SimpleAction::Play {
             trainer_card: match pokemon_center_lady {
                 Card::Trainer(tc) => tc,
                 _ => panic!("Expected trainer card"),
             },
         },
-        is_stack: false,
-    };
+        false,
+    );
     game.apply_action(&play_action);
 
     let state = game.get_state_clone();
@@ -203,16 +203,16 @@ fn test_pokemon_center_lady_heals_and_cures_together() {
     assert!(bulbasaur_before.asleep, "Should be asleep");
 
     // Act: Play Pokemon Center Lady and choose Bulbasaur
-    let play_action = Action {
-        actor: current_player,
-        action: SimpleAction::Play {
+    let play_action = Action::new(
+        current_player,
+        SimpleAction::Play {
             trainer_card: match pokemon_center_lady {
                 Card::Trainer(tc) => tc,
                 _ => panic!("Expected trainer card"),
             },
         },
-        is_stack: false,
-    };
+        false,
+    );
     game.apply_action(&play_action);
 
     let state = game.get_state_clone();
@@ -245,3 +245,106 @@ fn test_pokemon_center_lady_heals_and_cures_together() {
         "Bulbasaur should no longer be asleep"
     );
 }
+
+#[test]
+fn test_pokemon_center_lady_only_targets_damaged_or_afflicted() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    let current_player = state.current_player;
+
+    // Poisoned-but-full-HP active, healthy bench
+    let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+    let mut poisoned = PlayedCard::new(bulbasaur_card.clone(), 70, 70, vec![], false, vec![]);
+    poisoned.poisoned = true;
+    state.in_play_pokemon[current_player] = [
+        Some(poisoned),
+        Some(PlayedCard::new(
+            bulbasaur_card,
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let pokemon_center_lady = get_card_by_enum(CardId::A2b070PokemonCenterLady);
+    state.hands[current_player].push(pokemon_center_lady.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        current_player,
+        SimpleAction::Play {
+            trainer_card: pokemon_center_lady.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    let (_actor, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(
+        actions.len(),
+        1,
+        "Healthy bench Pokemon is not a valid target"
+    );
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    let active = state.get_active(current_player);
+    assert!(!active.poisoned);
+    assert_eq!(active.remaining_hp, 70);
+}
+
+#[test]
+fn test_pokemon_center_lady_targets_burned_pokemon() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    let current_player = state.current_player;
+
+    // Burned-but-full-HP active, healthy bench
+    let bulbasaur_card = get_card_by_enum(CardId::A1001Bulbasaur);
+    let mut burned = PlayedCard::new(bulbasaur_card.clone(), 70, 70, vec![], false, vec![]);
+    burned.burned = true;
+    state.in_play_pokemon[current_player] = [
+        Some(burned),
+        Some(PlayedCard::new(
+            bulbasaur_card,
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let pokemon_center_lady = get_card_by_enum(CardId::A2b070PokemonCenterLady);
+    state.hands[current_player] = vec![pokemon_center_lady.clone()];
+    state.move_generation_stack.clear(); // Skip the start-of-turn draw
+    game.set_state(state);
+
+    let (_actor, actions) = deckgym::generate_possible_actions(&game.get_state_clone());
+    assert!(
+        actions
+            .iter()
+            .any(|a| matches!(a.action, SimpleAction::Play { .. })),
+        "A burned Pokemon is a valid target"
+    );
+
+    game.apply_action(&Action::new(
+        current_player,
+        SimpleAction::Play {
+            trainer_card: pokemon_center_lady.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    let (_actor, actions) = deckgym::generate_possible_actions(&state);
+    assert_eq!(actions.len(), 1, "Only the burned Active is a valid target");
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert!(!state.get_active(current_player).burned);
+}
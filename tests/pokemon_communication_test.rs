@@ -0,0 +1,45 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+};
+
+mod common;
+
+#[test]
+fn test_pokemon_communication_swaps_hand_pokemon_with_deck_pokemon() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let charmander = get_card_by_enum(CardId::A1033Charmander);
+    let communication = get_card_by_enum(CardId::A2146PokemonCommunication);
+    state.hands[0] = vec![bulbasaur.clone(), communication.clone()];
+    state.decks[0].cards = vec![charmander.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: communication.as_trainer(),
+        },
+        false,
+    ));
+
+    // Choose which Pokémon from hand to swap
+    let (actor, actions) = deckgym::generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    assert_eq!(
+        actions.iter().map(|x| x.action.clone()).collect::<Vec<_>>(),
+        vec![SimpleAction::CommunicatePokemon {
+            hand_pokemon: bulbasaur.clone(),
+        }]
+    );
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.hands[0], vec![charmander]);
+    assert_eq!(state.decks[0].cards, vec![bulbasaur]);
+}
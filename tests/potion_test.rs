@@ -0,0 +1,73 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::PlayedCard,
+};
+
+mod common;
+
+#[test]
+fn test_potion_offers_only_damaged_targets() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            30,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            70,
+            70,
+            vec![],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(bulbasaur, 60, 70, vec![], false, vec![])),
+        None,
+    ];
+    let potion = get_card_by_enum(CardId::PA001Potion);
+    state.hands[0].push(potion.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: potion.as_trainer(),
+        },
+        false,
+    ));
+
+    let (actor, actions) = deckgym::generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(
+        choices,
+        vec![
+            SimpleAction::Heal {
+                in_play_idx: 0,
+                amount: 20,
+                cure_status: false,
+            },
+            SimpleAction::Heal {
+                in_play_idx: 2,
+                amount: 20,
+                cure_status: false,
+            },
+        ]
+    );
+
+    game.apply_action(&actions[1]);
+    let state = game.get_state_clone();
+    assert_eq!(state.get_remaining_hp(0, 0), 30);
+    assert_eq!(state.get_remaining_hp(0, 2), 70);
+}
@@ -0,0 +1,46 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::Card,
+};
+
+mod common;
+
+/// Plays Red Card against an opponent holding `hand_size` cards with `deck_size` cards in deck.
+/// Returns the opponent's (hand size, deck size) afterwards.
+fn opponent_hand_and_deck_after_red_card(hand_size: usize, deck_size: usize) -> (usize, usize) {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.hands[1] = vec![bulbasaur.clone(); hand_size];
+    state.decks[1].cards = vec![bulbasaur; deck_size];
+    let red_card = get_card_by_enum(CardId::PA006RedCard);
+    state.hands[0].push(red_card.clone());
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: red_card.as_trainer(),
+        },
+        false,
+    ));
+
+    let state = game.get_state_clone();
+    assert!(!state.hands[0].contains(&Card::Trainer(red_card.as_trainer())));
+    (state.hands[1].len(), state.decks[1].cards.len())
+}
+
+#[test]
+fn test_red_card_shuffles_opponent_hand_and_draws_three() {
+    assert_eq!(opponent_hand_and_deck_after_red_card(5, 10), (3, 12));
+}
+
+#[test]
+fn test_red_card_draws_fewer_from_small_deck() {
+    assert_eq!(opponent_hand_and_deck_after_red_card(1, 1), (2, 0));
+}
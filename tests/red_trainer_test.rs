@@ -0,0 +1,58 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+};
+
+mod common;
+
+/// Charmeleon's Fire Claws (60) against the given defender (given 200 HP to avoid K.O.s),
+/// optionally after playing Red. Returns the damage dealt.
+fn fire_claws_damage(defender: CardId, play_red: bool) -> u32 {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    state.in_play_pokemon[0][0] = Some(PlayedCard::new(
+        get_card_by_enum(CardId::A1034Charmeleon),
+        90,
+        90,
+        vec![EnergyType::Fire, EnergyType::Fire, EnergyType::Fire],
+        false,
+        vec![],
+    ));
+    state.in_play_pokemon[1][0] = Some(PlayedCard::new(
+        get_card_by_enum(defender),
+        200,
+        200,
+        vec![],
+        false,
+        vec![],
+    ));
+    let red = get_card_by_enum(CardId::A2b071Red);
+    state.hands[0].push(red.clone());
+    game.set_state(state);
+
+    if play_red {
+        game.apply_action(&Action::new(
+            0,
+            SimpleAction::Play {
+                trainer_card: red.as_trainer(),
+            },
+            false,
+        ));
+    }
+    game.apply_action(&Action::new(0, SimpleAction::Attack(0), false));
+    200 - game.get_state_clone().get_remaining_hp(1, 0)
+}
+
+#[test]
+fn test_red_adds_damage_only_against_pokemon_ex() {
+    // Squirtle is not a Pokémon ex, Blastoise ex is. Neither is weak to Fire.
+    assert_eq!(fire_claws_damage(CardId::A1053Squirtle, false), 60);
+    assert_eq!(fire_claws_damage(CardId::A1053Squirtle, true), 60);
+    assert_eq!(fire_claws_damage(CardId::A1056BlastoiseEx, false), 60);
+    assert_eq!(fire_claws_damage(CardId::A1056BlastoiseEx, true), 80);
+}
@@ -0,0 +1,91 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    tool_ids::ToolId,
+};
+
+mod common;
+
+#[test]
+fn test_repel_switches_opponent_basic_active_to_bench() {
+    let mut game = get_initialized_game(0);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[0] = [
+        Some(PlayedCard::new(
+            bulbasaur.clone(),
+            70,
+            70,
+            vec![EnergyType::Grass],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    let mut opponent_active = PlayedCard::new(
+        bulbasaur,
+        50,
+        70,
+        vec![EnergyType::Grass, EnergyType::Water],
+        false,
+        vec![],
+    );
+    opponent_active.attached_tools = vec![ToolId::A2148RockyHelmet];
+    state.in_play_pokemon[1] = [
+        Some(opponent_active),
+        None,
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+    ];
+    let repel = get_card_by_enum(CardId::A3a064Repel);
+    state.hands[0] = vec![repel.clone()];
+    game.set_state(state);
+
+    game.apply_action(&Action::new(
+        0,
+        SimpleAction::Play {
+            trainer_card: repel.as_trainer(),
+        },
+        false,
+    ));
+
+    // The opponent chooses their new Active
+    let state = game.get_state_clone();
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 1);
+    let choices: Vec<_> = actions.iter().map(|x| x.action.clone()).collect();
+    assert_eq!(choices, vec![SimpleAction::Activate { in_play_idx: 2 }]);
+    game.apply_action(&actions[0]);
+
+    let state = game.get_state_clone();
+    assert_eq!(state.get_active(1).get_name(), "Squirtle");
+    let benched = state.in_play_pokemon[1][2].as_ref().unwrap();
+    assert_eq!(benched.get_name(), "Bulbasaur");
+    assert_eq!(benched.remaining_hp, 50);
+    assert_eq!(
+        benched.attached_energy,
+        vec![EnergyType::Grass, EnergyType::Water]
+    );
+    assert_eq!(benched.attached_tools, vec![ToolId::A2148RockyHelmet]);
+
+    // The opponent's promotion doesn't use up the Repel player's retreat
+    let (actor, actions) = generate_possible_actions(&state);
+    assert_eq!(actor, 0);
+    assert!(actions.iter().any(|x| x.action == SimpleAction::Retreat(1)));
+}
@@ -0,0 +1,59 @@
+use deckgym::{
+    actions::SimpleAction,
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::EnergyType,
+    players::{EndTurnPlayer, Player, ScriptedPlayer},
+    test_helpers::load_test_decks,
+    Game,
+};
+
+#[test]
+fn test_scripted_player_follows_its_script() {
+    let (deck_a, deck_b) = load_test_decks();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let exeggcute = get_card_by_enum(CardId::A1021Exeggcute);
+    let attach_grass = SimpleAction::Attach {
+        attachments: vec![(1, EnergyType::Grass, 0)],
+        is_turn_energy: true,
+    };
+    let script = vec![
+        // Setup
+        SimpleAction::Place(exeggcute.clone(), 0),
+        SimpleAction::EndTurn,
+        // Turn 1: going first, there is no Energy to attach, so it ends the turn instead
+        SimpleAction::Place(bulbasaur.clone(), 1),
+        attach_grass.clone(),
+        // Turn 3
+        attach_grass.clone(),
+        SimpleAction::EndTurn,
+        // Turn 5, after which the script runs out and it just ends turns
+        attach_grass,
+    ];
+    let players: Vec<Box<dyn Player>> = vec![
+        Box::new(ScriptedPlayer {
+            deck: deck_a,
+            actions: script.into(),
+        }),
+        Box::new(EndTurnPlayer { deck: deck_b }),
+    ];
+    let mut game = Game::new(players, 3);
+    while game.get_state_clone().turn_count < 8 {
+        game.play_tick();
+    }
+
+    let state = game.get_state_clone();
+    assert_eq!(state.points, [0, 0]);
+    assert_eq!(state.winner, None);
+    let active = state.get_active(0);
+    assert_eq!(active.card, exeggcute);
+    assert_eq!(
+        active.attached_energy,
+        vec![EnergyType::Grass, EnergyType::Grass]
+    );
+    let bench: Vec<_> = state
+        .enumerate_bench_pokemon(0)
+        .map(|(i, pokemon)| (i, pokemon.card.clone()))
+        .collect();
+    assert_eq!(bench, vec![(1, bulbasaur)]);
+}
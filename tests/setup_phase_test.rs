@@ -0,0 +1,57 @@
+use deckgym::{
+    actions::SimpleAction, card_ids::CardId, database::get_card_by_enum, generate_possible_actions,
+    models::PlayedCard, players::Player, Game, State,
+};
+
+mod common;
+
+fn setup_state_with_hand(hand: Vec<CardId>) -> State {
+    let players: Vec<Box<dyn Player>> = common::init_random_players();
+    let game = Game::new(players, 3);
+    let mut state = game.get_state_clone();
+    assert_eq!(state.turn_count, 0);
+    let current_player = state.current_player;
+    state.hands[current_player] = hand.into_iter().map(get_card_by_enum).collect();
+    state
+}
+
+#[test]
+fn test_setup_only_offers_basic_pokemon_for_active() {
+    let state = setup_state_with_hand(vec![CardId::A1002Ivysaur, CardId::A1001Bulbasaur]);
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+
+    let (_, actions) = generate_possible_actions(&state);
+    let actions: Vec<SimpleAction> = actions.into_iter().map(|x| x.action).collect();
+
+    // Without an Active Pokémon, the only option is placing the Basic as Active
+    assert_eq!(actions, vec![SimpleAction::Place(bulbasaur, 0)]);
+}
+
+#[test]
+fn test_setup_bench_excludes_evolutions() {
+    let mut state = setup_state_with_hand(vec![CardId::A1002Ivysaur, CardId::A1001Bulbasaur]);
+    let current_player = state.current_player;
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    state.hands[current_player].retain(|card| card != &bulbasaur);
+    state.in_play_pokemon[current_player][0] =
+        Some(PlayedCard::new(bulbasaur, 70, 70, vec![], true, vec![]));
+
+    let (_, actions) = generate_possible_actions(&state);
+
+    // Ivysaur can neither be benched nor evolve Bulbasaur during setup
+    assert!(actions
+        .iter()
+        .all(|x| matches!(x.action, SimpleAction::EndTurn)));
+}
+
+#[test]
+fn test_setup_cannot_end_without_active() {
+    let state = setup_state_with_hand(vec![CardId::A1001Bulbasaur, CardId::A1001Bulbasaur]);
+
+    let (_, actions) = generate_possible_actions(&state);
+
+    assert!(!actions.is_empty());
+    assert!(actions
+        .iter()
+        .all(|x| !matches!(x.action, SimpleAction::EndTurn)));
+}
@@ -0,0 +1,88 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    generate_possible_actions,
+    models::{EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+/// Sets up player 0's turn with an Asleep Bulbasaur that could otherwise attack and retreat.
+fn game_with_asleep_active(seed: u64) -> Game<'static> {
+    let mut game = get_initialized_game(seed);
+    let mut state = game.get_state_clone();
+    state.current_player = 0;
+    state.turn_count = 3;
+    state.move_generation_stack.clear();
+    let bulbasaur = get_card_by_enum(CardId::A1001Bulbasaur);
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    let mut active = PlayedCard::new(
+        bulbasaur.clone(),
+        70,
+        70,
+        vec![EnergyType::Grass, EnergyType::Grass],
+        false,
+        vec![],
+    );
+    active.asleep = true;
+    state.in_play_pokemon[0] = [
+        Some(active),
+        Some(PlayedCard::new(
+            squirtle.clone(),
+            60,
+            60,
+            vec![],
+            false,
+            vec![],
+        )),
+        None,
+        None,
+    ];
+    state.in_play_pokemon[1] = [
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+        None,
+        None,
+    ];
+    game.set_state(state);
+    game
+}
+
+fn end_turn(game: &mut Game) {
+    game.apply_action(&Action::new(0, SimpleAction::EndTurn, false));
+}
+
+#[test]
+fn test_asleep_pokemon_cannot_attack_or_retreat() {
+    let game = game_with_asleep_active(0);
+    let (actor, actions) = generate_possible_actions(&game.get_state_clone());
+    assert_eq!(actor, 0);
+    assert!(!actions
+        .iter()
+        .any(|x| matches!(x.action, SimpleAction::Attack(_) | SimpleAction::Retreat(_))));
+
+    // Once awake, the same Pokémon can do both again
+    let mut state = game.get_state_clone();
+    state.in_play_pokemon[0][0].as_mut().unwrap().asleep = false;
+    let (_, actions) = generate_possible_actions(&state);
+    assert!(actions.iter().any(|x| x.action == SimpleAction::Attack(0)));
+    assert!(actions.iter().any(|x| x.action == SimpleAction::Retreat(1)));
+}
+
+#[test]
+fn test_sleep_persists_on_tails() {
+    // Pokémon Checkup flips a coin for every Asleep Pokémon between turns
+    let mut game = game_with_asleep_active(2);
+    end_turn(&mut game);
+    assert!(game.get_state_clone().get_active(0).asleep);
+}
+
+#[test]
+fn test_sleep_clears_on_heads() {
+    let mut game = game_with_asleep_active(0);
+    end_turn(&mut game);
+    assert!(!game.get_state_clone().get_active(0).asleep);
+}
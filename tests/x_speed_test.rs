@@ -0,0 +1,97 @@
+use common::get_initialized_game;
+use deckgym::{
+    actions::{Action, SimpleAction},
+    card_ids::CardId,
+    database::get_card_by_enum,
+    models::{EnergyType, PlayedCard},
+    Game,
+};
+
+mod common;
+
+/// Puts `active_id` (with `energies` Water energy attached) in `player`'s Active Spot with a
+/// benched Squirtle, and gives them the given Trainer cards in hand.
+fn setup(game: &mut Game, player: usize, active_id: CardId, energies: usize, hand: &[CardId]) {
+    let mut state = game.get_state_clone();
+    state.current_player = player;
+    state.move_generation_stack.clear();
+    let squirtle = get_card_by_enum(CardId::A1053Squirtle);
+    state.in_play_pokemon[player] = [
+        Some(PlayedCard::new(
+            get_card_by_enum(active_id),
+            60,
+            60,
+            vec![EnergyType::Water; energies],
+            false,
+            vec![],
+        )),
+        Some(PlayedCard::new(squirtle, 60, 60, vec![], false, vec![])),
+        None,
+        None,
+    ];
+    state.hands[player] = hand.iter().map(|id| get_card_by_enum(*id)).collect();
+    game.set_state(state);
+}
+
+fn play_trainers(game: &mut Game, player: usize, trainers: &[CardId]) {
+    for trainer in trainers {
+        game.apply_action(&Action::new(
+            player,
+            SimpleAction::Play {
+                trainer_card: get_card_by_enum(*trainer).as_trainer(),
+            },
+            false,
+        ));
+    }
+}
+
+/// Retreats `player`'s Active into the Bench and returns its remaining energies.
+fn retreat(game: &mut Game, player: usize) -> usize {
+    game.apply_action(&Action::new(player, SimpleAction::Retreat(1), false));
+    game.get_state_clone().in_play_pokemon[player][1]
+        .as_ref()
+        .expect("Retreated Pokémon should be on the Bench")
+        .attached_energy
+        .len()
+}
+
+#[test]
+fn test_x_speed_makes_one_energy_retreat_free() {
+    let mut game = get_initialized_game(0);
+    setup(
+        &mut game,
+        0,
+        CardId::A1053Squirtle,
+        1,
+        &[CardId::PA002XSpeed],
+    );
+    play_trainers(&mut game, 0, &[CardId::PA002XSpeed]);
+    assert_eq!(retreat(&mut game, 0), 1);
+}
+
+#[test]
+fn test_x_speed_stacks_with_leaf() {
+    // Blastoise has a retreat cost of 3
+    let trainers = [CardId::PA002XSpeed, CardId::A1a068Leaf];
+    let mut game = get_initialized_game(0);
+    setup(&mut game, 0, CardId::A1055Blastoise, 3, &trainers);
+    play_trainers(&mut game, 0, &trainers);
+    assert_eq!(retreat(&mut game, 0), 3);
+}
+
+#[test]
+fn test_x_speed_wears_off_at_end_of_turn() {
+    let mut game = get_initialized_game(0);
+    setup(
+        &mut game,
+        0,
+        CardId::A1053Squirtle,
+        1,
+        &[CardId::PA002XSpeed],
+    );
+    play_trainers(&mut game, 0, &[CardId::PA002XSpeed]);
+    game.apply_action(&Action::new(0, SimpleAction::EndTurn, false));
+
+    setup(&mut game, 1, CardId::A1053Squirtle, 1, &[]);
+    assert_eq!(retreat(&mut game, 1), 0);
+}